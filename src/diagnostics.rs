@@ -0,0 +1,104 @@
+//! Collecting batches of `AdHocResult`s for reporting.
+
+use crate::AdHocResult;
+
+/// A report sorting a batch of results into buckets by variant.
+///
+/// A `Report` can be built with `extend` or by collecting an iterator of
+/// `AdHocResult`s. Each element keeps its payload and is routed into the
+/// bucket matching its variant, in iteration order.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::AdHocResult;
+/// use ad_hoc_result::diagnostics::Report;
+///
+/// let results = vec![
+///     AdHocResult::Ok(1),
+///     AdHocResult::AdHoc(2, "Not ideal"),
+///     AdHocResult::Err("Error"),
+///     AdHocResult::Ok(4),
+/// ];
+///
+/// let mut report: Report<_, _> = results.into_iter().collect();
+/// assert_eq!(report.ok, [1, 4]);
+/// assert_eq!(report.adhoc, [(2, "Not ideal")]);
+/// assert_eq!(report.err, ["Error"]);
+///
+/// report.extend(vec![AdHocResult::AdHoc(5, "Slow"), AdHocResult::Ok(6)]);
+/// assert_eq!(report.ok, [1, 4, 6]);
+/// assert_eq!(report.adhoc, [(2, "Not ideal"), (5, "Slow")]);
+/// assert_eq!(report.err, ["Error"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report<T, E> {
+    /// Values of the `Ok` results
+    pub ok: Vec<T>,
+    /// Recommended values and errors of the `AdHoc` results
+    pub adhoc: Vec<(T, E)>,
+    /// Errors of the `Err` results
+    pub err: Vec<E>,
+}
+
+impl<T, E> Report<T, E> {
+    /// Creates an empty `Report`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::diagnostics::Report;
+    ///
+    /// let report: Report<u32, &str> = Report::new();
+    /// assert!(report.ok.is_empty() && report.adhoc.is_empty() && report.err.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Report {
+            ok: Vec::new(),
+            adhoc: Vec::new(),
+            err: Vec::new(),
+        }
+    }
+
+    /// Adds a single result to the bucket matching its variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    /// use ad_hoc_result::diagnostics::Report;
+    ///
+    /// let mut report = Report::new();
+    /// report.push(AdHocResult::AdHoc(2, "Not ideal"));
+    /// assert_eq!(report.adhoc, [(2, "Not ideal")]);
+    /// ```
+    pub fn push(&mut self, result: AdHocResult<T, E>) {
+        match result {
+            AdHocResult::Ok(v) => self.ok.push(v),
+            AdHocResult::AdHoc(v, e) => self.adhoc.push((v, e)),
+            AdHocResult::Err(e) => self.err.push(e),
+        }
+    }
+}
+
+impl<T, E> Default for Report<T, E> {
+    fn default() -> Self {
+        Report::new()
+    }
+}
+
+impl<T, E> Extend<AdHocResult<T, E>> for Report<T, E> {
+    fn extend<I: IntoIterator<Item = AdHocResult<T, E>>>(&mut self, iter: I) {
+        for result in iter {
+            self.push(result);
+        }
+    }
+}
+
+impl<T, E> FromIterator<AdHocResult<T, E>> for Report<T, E> {
+    fn from_iter<I: IntoIterator<Item = AdHocResult<T, E>>>(iter: I) -> Self {
+        let mut report = Report::new();
+        report.extend(iter);
+        report
+    }
+}
@@ -8,6 +8,9 @@
 //! For example, this is needed when you can solve a linear system, but the accuracy is poor due to large condition numbers: you may want to return the computed solution as a recommendation, even though the operation is technically a failure.
 //! This is an interface to recommend a value anyway when computation fails.
 
+pub mod diagnostics;
+pub mod numeric;
+
 /// An enum representing either success (`Ok`), failure with a recommended value (`AdHoc`),
 /// or complete failure (`Err`).
 /// 
@@ -36,6 +39,59 @@
 /// let ad_hoc_result = divide(10.0, 0.0);
 /// assert_eq!(ad_hoc_result.unwrap_adhoc(), f64::INFINITY);
 /// ```
+///
+/// Two `AdHoc` values are equal only when both their recommended values and their
+/// errors are equal:
+///
+/// ```
+/// use ad_hoc_result::AdHocResult;
+///
+/// let a: AdHocResult<i32, &str> = AdHocResult::AdHoc(1, "a");
+/// assert_eq!(a, AdHocResult::AdHoc(1, "a"));
+/// assert_ne!(a, AdHocResult::AdHoc(1, "b"));
+/// assert_ne!(a, AdHocResult::AdHoc(2, "a"));
+/// assert_ne!(a, AdHocResult::Ok(1));
+///
+/// let mut original: AdHocResult<Vec<i32>, String> = AdHocResult::AdHoc(vec![1, 2], "Not ideal".to_string());
+/// let copy = original.clone();
+/// assert_eq!(copy, original);
+/// if let AdHocResult::AdHoc(v, _) = &mut original {
+///     v.push(3);
+/// }
+/// assert_eq!(copy, AdHocResult::AdHoc(vec![1, 2], "Not ideal".to_string()));
+/// assert_ne!(copy, original);
+/// assert_eq!(format!("{copy:?}"), r#"AdHoc([1, 2], "Not ideal")"#);
+/// ```
+///
+/// # Serialization
+///
+/// With the `serde` feature, `AdHocResult` implements `Serialize` and `Deserialize`
+/// using an externally tagged representation: `{"Ok": value}`, `{"AdHoc": [value, error]}`,
+/// or `{"Err": error}`. Every variant round-trips, including both fields of an `AdHoc`:
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use ad_hoc_result::AdHocResult;
+///
+/// let results: [AdHocResult<f64, String>; 3] = [
+///     AdHocResult::Ok(1.5),
+///     AdHocResult::AdHoc(1.5, "Low precision".to_string()),
+///     AdHocResult::Err("Singular".to_string()),
+/// ];
+///
+/// for result in results {
+///     let json = serde_json::to_string(&result).unwrap();
+///     let back: AdHocResult<f64, String> = serde_json::from_str(&json).unwrap();
+///     assert_eq!(back, result);
+/// }
+///
+/// let adhoc: AdHocResult<f64, String> = AdHocResult::AdHoc(1.5, "Low precision".to_string());
+/// assert_eq!(serde_json::to_string(&adhoc).unwrap(), r#"{"AdHoc":[1.5,"Low precision"]}"#);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AdHocResult<T, E> {
     /// Contains the success value
     Ok(T),
@@ -45,6 +101,56 @@ pub enum AdHocResult<T, E> {
     Err(E)
 }
 
+/// The variant of an `AdHocResult`, without its payloads.
+///
+/// Kinds are ordered from best to worst: `Ok < AdHoc < Err`, so the worst of
+/// several kinds is their maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ResultKind {
+    /// The result is an `Ok`
+    Ok,
+    /// The result is an `AdHoc`
+    AdHoc,
+    /// The result is an `Err`
+    Err,
+}
+
+/// Where the error of a collapsed `AdHocResult` came from.
+///
+/// Returned by `to_result_tagged` so that `Result`-consuming code can still tell
+/// a degraded result apart from a hard failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Origin {
+    /// The error came from an `AdHoc`, whose recommended value was discarded
+    Recommendation,
+    /// The error came from an `Err`
+    Fatal,
+}
+
+/// Where a value extracted by `resolve` came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueSource {
+    /// The value came from an `Ok`
+    Clean,
+    /// The value is the recommendation of an `AdHoc`
+    Recommended,
+    /// The value was recovered from the error of an `Err`
+    Recovered,
+}
+
+/// How serious an error is, for results using `(Severity, String)` errors.
+///
+/// Severities are ordered from `Info` (least serious) to `Critical` (most serious).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Informational, the value can be used as-is
+    Info,
+    /// The value is usable but should be treated with caution
+    Warning,
+    /// The value should not be trusted
+    Critical,
+}
+
 impl<T, E> AdHocResult<T, E> {
     /// Unwraps a result, yielding the content of an `Ok`.
     ///
@@ -125,6 +231,285 @@ impl<T, E> AdHocResult<T, E> {
         self.expect_adhoc("Unwrap fails")
     }
 
+    /// Returns the content of an `Ok` or `AdHoc`, or the provided default for an `Err`.
+    ///
+    /// The recommended value of an `AdHoc` is returned rather than the default,
+    /// since recommending a usable value is the whole point of that variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<u32, &str> = AdHocResult::Ok(2);
+    /// assert_eq!(ok.unwrap_or(0), 2);
+    ///
+    /// let adhoc: AdHocResult<u32, &str> = AdHocResult::AdHoc(7, "warn");
+    /// assert_eq!(adhoc.unwrap_or(0), 7);
+    ///
+    /// let err: AdHocResult<u32, &str> = AdHocResult::Err("x");
+    /// assert_eq!(err.unwrap_or(0), 0);
+    /// ```
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            AdHocResult::Ok(x) => x,
+            AdHocResult::AdHoc(x, _) => x,
+            AdHocResult::Err(_) => default,
+        }
+    }
+
+    /// Returns the content of an `Ok` or `AdHoc`, or computes it from the error of an `Err`.
+    ///
+    /// As with `unwrap_or`, the recommended value of an `AdHoc` is returned and `f`
+    /// is only called for an `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let adhoc: AdHocResult<usize, &str> = AdHocResult::AdHoc(7, "warn");
+    /// assert_eq!(adhoc.unwrap_or_else(str::len), 7);
+    ///
+    /// let err: AdHocResult<usize, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(err.unwrap_or_else(str::len), 5);
+    /// ```
+    pub fn unwrap_or_else<F: FnOnce(E) -> T>(self, f: F) -> T {
+        match self {
+            AdHocResult::Ok(x) => x,
+            AdHocResult::AdHoc(x, _) => x,
+            AdHocResult::Err(e) => f(e),
+        }
+    }
+
+    /// Returns the content of an `Ok` or `AdHoc`, or `T::default()` for an `Err`.
+    ///
+    /// As with `unwrap_or`, the recommended value of an `AdHoc` is returned rather
+    /// than the default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let adhoc: AdHocResult<u32, &str> = AdHocResult::AdHoc(7, "warn");
+    /// assert_eq!(adhoc.unwrap_or_default(), 7);
+    ///
+    /// let err: AdHocResult<u32, &str> = AdHocResult::Err("x");
+    /// assert_eq!(err.unwrap_or_default(), 0);
+    /// ```
+    pub fn unwrap_or_default(self) -> T
+    where
+        T: Default,
+    {
+        self.unwrap_or_else(|_| T::default())
+    }
+
+    /// Unwraps a result, yielding the error of an `AdHoc` or `Err`.
+    ///
+    /// For an `AdHoc`, the recommended value is discarded.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the provided message if the value is an `Ok`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let x: AdHocResult<u32, &str> = AdHocResult::AdHoc(2, "Not ideal");
+    /// assert_eq!(x.expect_err("Testing expect_err"), "Not ideal");
+    /// ```
+    pub fn expect_err(self, message: &str) -> E {
+        match self {
+            AdHocResult::AdHoc(_, e) => e,
+            AdHocResult::Err(e) => e,
+            _ => panic!("{}", message)
+        }
+    }
+
+    /// Unwraps a result, yielding the error of an `AdHoc` or `Err`.
+    ///
+    /// For an `AdHoc`, the recommended value is discarded.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a generic "Unwrap fails" message if the value is an `Ok`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let adhoc: AdHocResult<u32, &str> = AdHocResult::AdHoc(2, "Not ideal");
+    /// assert_eq!(adhoc.unwrap_err(), "Not ideal");
+    ///
+    /// let err: AdHocResult<u32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(err.unwrap_err(), "Error");
+    ///
+    /// let ok: AdHocResult<u32, &str> = AdHocResult::Ok(2);
+    /// assert!(std::panic::catch_unwind(|| ok.unwrap_err()).is_err());
+    /// ```
+    pub fn unwrap_err(self) -> E {
+        self.expect_err("Unwrap fails")
+    }
+
+    /// Decomposes the result into its optional value and optional error.
+    ///
+    /// `Ok` gives `(Some(v), None)`, `AdHoc` gives `(Some(v), Some(e))`, and
+    /// `Err` gives `(None, Some(e))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<u32, &str> = AdHocResult::Ok(2);
+    /// assert_eq!(ok.into_parts(), (Some(2), None));
+    ///
+    /// let adhoc: AdHocResult<u32, &str> = AdHocResult::AdHoc(2, "Not ideal");
+    /// assert_eq!(adhoc.into_parts(), (Some(2), Some("Not ideal")));
+    ///
+    /// let err: AdHocResult<u32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(err.into_parts(), (None, Some("Error")));
+    /// ```
+    pub fn into_parts(self) -> (Option<T>, Option<E>) {
+        match self {
+            AdHocResult::Ok(v) => (Some(v), None),
+            AdHocResult::AdHoc(v, e) => (Some(v), Some(e)),
+            AdHocResult::Err(e) => (None, Some(e)),
+        }
+    }
+
+    /// Returns the content of an `Ok` or `AdHoc`, or `T::default()` for an `Err`.
+    ///
+    /// Falling back on an `Err` emits a `log::warn!` record containing the error,
+    /// so that silent fallbacks remain visible in production.
+    ///
+    /// Requires the `log` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    /// use std::sync::Mutex;
+    ///
+    /// static LINES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    ///
+    /// struct Capture;
+    ///
+    /// impl log::Log for Capture {
+    ///     fn enabled(&self, _: &log::Metadata) -> bool { true }
+    ///     fn log(&self, record: &log::Record) {
+    ///         LINES.lock().unwrap().push(format!("{} {}", record.level(), record.args()));
+    ///     }
+    ///     fn flush(&self) {}
+    /// }
+    ///
+    /// log::set_logger(&Capture).unwrap();
+    /// log::set_max_level(log::LevelFilter::Trace);
+    ///
+    /// let adhoc: AdHocResult<u32, &str> = AdHocResult::AdHoc(2, "Not ideal");
+    /// assert_eq!(adhoc.value_or_default_logged(), 2);
+    /// assert!(LINES.lock().unwrap().is_empty());
+    ///
+    /// let err: AdHocResult<u32, &str> = AdHocResult::Err("Solver diverged");
+    /// assert_eq!(err.value_or_default_logged(), 0);
+    /// assert_eq!(*LINES.lock().unwrap(), ["WARN falling back to default: Solver diverged"]);
+    /// ```
+    #[cfg(feature = "log")]
+    pub fn value_or_default_logged(self) -> T
+    where
+        T: Default,
+        E: std::fmt::Display,
+    {
+        match self {
+            AdHocResult::Ok(v) => v,
+            AdHocResult::AdHoc(v, _) => v,
+            AdHocResult::Err(e) => {
+                log::warn!("falling back to default: {e}");
+                T::default()
+            }
+        }
+    }
+
+    /// Emits a `tracing` event for the error of an `AdHoc` or `Err` and returns `self` unchanged.
+    ///
+    /// The error of an `AdHoc` is reported with `tracing::warn!`, and the error of an
+    /// `Err` with `tracing::error!`. Nothing is emitted for an `Ok`. The provided
+    /// `target` is attached to the event as a field named `source`; it is not the
+    /// tracing target of the event, so filter on the field rather than with
+    /// target-based directives.
+    ///
+    /// Requires the `tracing` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    /// use std::sync::{Arc, Mutex};
+    /// use tracing::field::{Field, Visit};
+    /// use tracing::{span, Event, Level, Metadata, Subscriber};
+    ///
+    /// #[derive(Default)]
+    /// struct Source(Option<String>);
+    ///
+    /// impl Visit for Source {
+    ///     fn record_str(&mut self, field: &Field, value: &str) {
+    ///         if field.name() == "source" {
+    ///             self.0 = Some(value.to_string());
+    ///         }
+    ///     }
+    ///     fn record_debug(&mut self, _: &Field, _: &dyn std::fmt::Debug) {}
+    /// }
+    ///
+    /// #[derive(Clone, Default)]
+    /// struct Capture(Arc<Mutex<Vec<(Level, Option<String>)>>>);
+    ///
+    /// impl Subscriber for Capture {
+    ///     fn enabled(&self, _: &Metadata<'_>) -> bool { true }
+    ///     fn new_span(&self, _: &span::Attributes<'_>) -> span::Id { span::Id::from_u64(1) }
+    ///     fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+    ///     fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+    ///     fn event(&self, event: &Event<'_>) {
+    ///         let mut source = Source::default();
+    ///         event.record(&mut source);
+    ///         self.0.lock().unwrap().push((*event.metadata().level(), source.0));
+    ///     }
+    ///     fn enter(&self, _: &span::Id) {}
+    ///     fn exit(&self, _: &span::Id) {}
+    /// }
+    ///
+    /// let capture = Capture::default();
+    /// tracing::subscriber::with_default(capture.clone(), || {
+    ///     let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    ///     let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    ///     let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    ///
+    ///     assert!(matches!(ok.log_errors("solver"), AdHocResult::Ok(42)));
+    ///     assert!(matches!(adhoc.log_errors("solver"), AdHocResult::AdHoc(42, "Not perfect")));
+    ///     assert!(matches!(err.log_errors("solver"), AdHocResult::Err("Error")));
+    /// });
+    ///
+    /// assert_eq!(*capture.0.lock().unwrap(), [
+    ///     (Level::WARN, Some("solver".to_string())),
+    ///     (Level::ERROR, Some("solver".to_string())),
+    /// ]);
+    /// ```
+    #[cfg(feature = "tracing")]
+    pub fn log_errors(self, target: &str) -> Self
+    where
+        E: std::fmt::Display,
+    {
+        match &self {
+            AdHocResult::Ok(_) => {}
+            AdHocResult::AdHoc(_, e) => tracing::warn!(source = target, "{e}"),
+            AdHocResult::Err(e) => tracing::error!(source = target, "{e}"),
+        }
+        self
+    }
+
     /// Converts the `AdHocResult<T, E>` into a `Result<T, E>`.
     ///
     /// This conversion treats both `Err` and `AdHoc` variants as errors,
@@ -179,113 +564,3321 @@ impl<T, E> AdHocResult<T, E> {
             AdHocResult::Err(e) => Err(e),
         }
     }
-}
 
-impl<T, E> From<Result<T, E>> for AdHocResult<T, E> {
-    /// Converts a `Result<T, E>` into an `AdHocResult<T, E>`.
+    /// Borrows the `AdHocResult<T, E>` as a `Result<&T, &E>` treating `AdHoc` as success.
     ///
-    /// This conversion maps `Ok` variants to `AdHocResult::Ok` and
-    /// `Err` variants to `AdHocResult::Err`.
+    /// This is the borrowing counterpart of `to_result_with_adhoc`: the recommended
+    /// value of an `AdHoc` is returned as `Ok`, and its error is not visible.
     ///
     /// # Examples
     ///
     /// ```
     /// use ad_hoc_result::AdHocResult;
     ///
-    /// let ok_result: Result<i32, &str> = Ok(42);
-    /// let adhoc_ok: AdHocResult<i32, &str> = ok_result.into();
-    /// 
-    /// match adhoc_ok {
-    ///     AdHocResult::Ok(v) => assert_eq!(v, 42),
-    ///     _ => panic!("Expected AdHocResult::Ok"),
-    /// }
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert_eq!(ok.as_result_lenient(), Ok(&42));
     ///
-    /// let err_result: Result<i32, &str> = Err("Error");
-    /// let adhoc_err: AdHocResult<i32, &str> = err_result.into();
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert_eq!(adhoc.as_result_lenient(), Ok(&42));
     ///
-    /// match adhoc_err {
-    ///     AdHocResult::Err(e) => assert_eq!(e, "Error"),
-    ///     _ => panic!("Expected AdHocResult::Err"),
-    /// }
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(err.as_result_lenient(), Err(&"Error"));
     /// ```
-    fn from(result: Result<T, E>) -> Self {
-        match result {
-            Ok(v) => AdHocResult::Ok(v),
-            Err(e) => AdHocResult::Err(e),
+    pub fn as_result_lenient(&self) -> Result<&T, &E> {
+        match self {
+            AdHocResult::Ok(v) => Ok(v),
+            AdHocResult::AdHoc(v, _) => Ok(v),
+            AdHocResult::Err(e) => Err(e),
         }
     }
-}
 
-impl<T, E> From<AdHocResult<T, E>> for Result<T, E> {
-    /// Converts an `AdHocResult<T, E>` into a `Result<T, E>`.
+    /// Borrows the `AdHocResult<T, E>` as a `Result<&T, &E>` treating `AdHoc` as an error.
     ///
-    /// This conversion maps `AdHocResult::Ok` to `Ok` and both
-    /// `AdHocResult::AdHoc` and `AdHocResult::Err` to `Err`.
-    /// For `AdHoc`, the recommended value is discarded.
+    /// This is the borrowing counterpart of `to_result`: the error of an `AdHoc`
+    /// is returned as `Err`, and its recommended value is not visible.
     ///
     /// # Examples
     ///
     /// ```
     /// use ad_hoc_result::AdHocResult;
     ///
-    /// let adhoc_ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
-    /// let ok_result: Result<i32, &str> = adhoc_ok.into();
-    /// assert_eq!(ok_result, Ok(42));
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert_eq!(ok.as_result_strict(), Ok(&42));
     ///
     /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
-    /// let adhoc_result: Result<i32, &str> = adhoc.into();
-    /// assert_eq!(adhoc_result, Err("Not perfect"));
+    /// assert_eq!(adhoc.as_result_strict(), Err(&"Not perfect"));
     ///
-    /// let adhoc_err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
-    /// let err_result: Result<i32, &str> = adhoc_err.into();
-    /// assert_eq!(err_result, Err("Error"));
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(err.as_result_strict(), Err(&"Error"));
     /// ```
-    fn from(adhoc: AdHocResult<T, E>) -> Self {
-        adhoc.to_result()
+    pub fn as_result_strict(&self) -> Result<&T, &E> {
+        match self {
+            AdHocResult::Ok(v) => Ok(v),
+            AdHocResult::AdHoc(_, e) => Err(e),
+            AdHocResult::Err(e) => Err(e),
+        }
     }
-}
 
-// Additional helper trait implementation
-impl<T, E> AdHocResult<T, E> {
-    /// Creates a new `AdHocResult` in the `Ok` variant.
+    /// Serializes the `AdHocResult<T, E>` into a `serde_json::Value`.
+    ///
+    /// The value is externally tagged by variant name: an `Ok` becomes `{"Ok": value}`,
+    /// an `AdHoc` becomes `{"AdHoc": [value, error]}`, and an `Err` becomes `{"Err": error}`.
+    ///
+    /// Requires the `serde` feature.
     ///
     /// # Examples
     ///
     /// ```
     /// use ad_hoc_result::AdHocResult;
+    /// use serde_json::json;
     ///
-    /// let x: AdHocResult<u32, &str> = AdHocResult::new_ok(42);
-    /// assert_eq!(x.unwrap(), 42);
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert_eq!(ok.to_json_value().unwrap(), json!({"Ok": 42}));
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert_eq!(adhoc.to_json_value().unwrap(), json!({"AdHoc": [42, "Not perfect"]}));
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(err.to_json_value().unwrap(), json!({"Err": "Error"}));
     /// ```
-    pub fn new_ok(value: T) -> Self {
-        AdHocResult::Ok(value)
+    #[cfg(feature = "serde")]
+    pub fn to_json_value(&self) -> Result<serde_json::Value, serde_json::Error>
+    where
+        T: serde::Serialize,
+        E: serde::Serialize,
+    {
+        serde_json::to_value(self)
     }
 
-    /// Creates a new `AdHocResult` in the `AdHoc` variant with a recommended value and an error.
+    /// Attempts to recover from an `Err` with a fallible closure.
+    ///
+    /// For an `Err(e)`, `f(e)` is called: `Ok(v)` becomes `AdHocResult::Ok(v)` and
+    /// `Err(e2)` becomes `AdHocResult::Err(e2)`. An `AdHoc` already carries a usable
+    /// recommendation, so it is kept as-is and `f` is not called. `Ok` is also returned unchanged.
     ///
     /// # Examples
     ///
     /// ```
     /// use ad_hoc_result::AdHocResult;
     ///
-    /// let x: AdHocResult<u32, &str> = AdHocResult::new_adhoc(42, "Not ideal");
-    /// assert_eq!(x.unwrap_adhoc(), 42);
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Primary solver failed");
+    /// let recovered = err.or_try(|_| Ok(7));
+    /// assert!(matches!(recovered, AdHocResult::Ok(7)));
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Primary solver failed");
+    /// let still_failed = err.or_try(|_| Err("Fallback solver failed"));
+    /// assert!(matches!(still_failed, AdHocResult::Err("Fallback solver failed")));
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// let kept = adhoc.or_try(|_| Ok(7));
+    /// assert!(matches!(kept, AdHocResult::AdHoc(42, "Not perfect")));
     /// ```
-    pub fn new_adhoc(value: T, error: E) -> Self {
-        AdHocResult::AdHoc(value, error)
+    pub fn or_try<F: FnOnce(E) -> Result<T, E>>(self, f: F) -> Self {
+        match self {
+            AdHocResult::Err(e) => f(e).into(),
+            other => other,
+        }
     }
 
-    /// Creates a new `AdHocResult` in the `Err` variant.
+    /// Tries to improve the value with a fallible closure, degrading to `AdHoc` on failure.
+    ///
+    /// The closure receives a clone of the value, so the original is still available
+    /// if improving fails. For an `Ok(v)`, `f(v.clone())` returning `Ok(u)` gives `Ok(u)`,
+    /// while returning `Err(e)` gives `AdHoc(v, e)` with the original value as a recommendation.
+    /// For an `AdHoc(v, e)`, a successful improvement gives `AdHoc(u, e)` keeping the warning,
+    /// and a failed one leaves the result unchanged. `Err` is returned unchanged and `f` is not called.
     ///
     /// # Examples
     ///
     /// ```
     /// use ad_hoc_result::AdHocResult;
     ///
-    /// let x: AdHocResult<u32, &str> = AdHocResult::new_err("Error occurred");
-    /// assert!(matches!(x, AdHocResult::Err(_)));
+    /// fn refine(x: f64) -> Result<f64, &'static str> {
+    ///     if x > 0.0 { Ok(x.sqrt()) } else { Err("Refinement failed") }
+    /// }
+    ///
+    /// let ok: AdHocResult<f64, &str> = AdHocResult::Ok(4.0);
+    /// assert!(matches!(ok.map_or_degrade(refine), AdHocResult::Ok(2.0)));
+    ///
+    /// let ok: AdHocResult<f64, &str> = AdHocResult::Ok(-4.0);
+    /// assert!(matches!(ok.map_or_degrade(refine), AdHocResult::AdHoc(-4.0, "Refinement failed")));
+    ///
+    /// let adhoc: AdHocResult<f64, &str> = AdHocResult::AdHoc(9.0, "Not perfect");
+    /// assert!(matches!(adhoc.map_or_degrade(refine), AdHocResult::AdHoc(3.0, "Not perfect")));
     /// ```
-    pub fn new_err(error: E) -> Self {
-        AdHocResult::Err(error)
+    pub fn map_or_degrade<F: FnOnce(T) -> Result<T, E>>(self, f: F) -> Self
+    where
+        T: Clone,
+    {
+        match self {
+            AdHocResult::Ok(v) => match f(v.clone()) {
+                Ok(u) => AdHocResult::Ok(u),
+                Err(e) => AdHocResult::AdHoc(v, e),
+            },
+            AdHocResult::AdHoc(v, e) => match f(v.clone()) {
+                Ok(u) => AdHocResult::AdHoc(u, e),
+                Err(_) => AdHocResult::AdHoc(v, e),
+            },
+            AdHocResult::Err(e) => AdHocResult::Err(e),
+        }
+    }
+
+    /// Returns a process exit code for the result, with a custom code for `AdHoc`.
+    ///
+    /// `Ok` gives `0`, `AdHoc` gives `adhoc_code`, and `Err` gives `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert_eq!(adhoc.exit_code_with(3), 3);
+    /// ```
+    pub fn exit_code_with(&self, adhoc_code: i32) -> i32 {
+        match self {
+            AdHocResult::Ok(_) => 0,
+            AdHocResult::AdHoc(_, _) => adhoc_code,
+            AdHocResult::Err(_) => 1,
+        }
+    }
+
+    /// Returns a process exit code for the result, treating `AdHoc` as non-fatal.
+    ///
+    /// `Ok` and `AdHoc` give `0`, and `Err` gives `1`. This allows calling
+    /// `std::process::exit(result.exit_code())` without implementing `Termination`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert_eq!(ok.exit_code(), 0);
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert_eq!(adhoc.exit_code(), 0);
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(err.exit_code(), 1);
+    /// ```
+    pub fn exit_code(&self) -> i32 {
+        self.exit_code_with(0)
+    }
+
+    /// Returns a process exit code for the result, distinguishing `AdHoc` from success.
+    ///
+    /// `Ok` gives `0`, `AdHoc` gives `2`, and `Err` gives `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert_eq!(ok.exit_code_strict(), 0);
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert_eq!(adhoc.exit_code_strict(), 2);
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(err.exit_code_strict(), 1);
+    /// ```
+    pub fn exit_code_strict(&self) -> i32 {
+        self.exit_code_with(2)
+    }
+
+    /// Converts the `AdHocResult<T, E>` into a `Result<T, (Origin, E)>`, tagging each error with its origin.
+    ///
+    /// Like `to_result`, this treats `AdHoc` as an error and discards its recommended
+    /// value, but the error is tagged with `Origin::Recommendation`. The error of an `Err`
+    /// is tagged with `Origin::Fatal`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::{AdHocResult, Origin};
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert_eq!(ok.to_result_tagged(), Ok(42));
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert_eq!(adhoc.to_result_tagged(), Err((Origin::Recommendation, "Not perfect")));
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(err.to_result_tagged(), Err((Origin::Fatal, "Error")));
+    /// ```
+    pub fn to_result_tagged(self) -> Result<T, (Origin, E)> {
+        match self {
+            AdHocResult::Ok(v) => Ok(v),
+            AdHocResult::AdHoc(_, e) => Err((Origin::Recommendation, e)),
+            AdHocResult::Err(e) => Err((Origin::Fatal, e)),
+        }
+    }
+
+    /// Extracts a value from any variant, reporting where it came from.
+    ///
+    /// `Ok(v)` gives `(v, ValueSource::Clean)` and `AdHoc(v, _)` gives
+    /// `(v, ValueSource::Recommended)`. For `Err(e)`, the value is produced by
+    /// `on_err(e)` and tagged `ValueSource::Recovered`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::{AdHocResult, ValueSource};
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert_eq!(ok.resolve(|_| 0), (42, ValueSource::Clean));
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert_eq!(adhoc.resolve(|_| 0), (42, ValueSource::Recommended));
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(err.resolve(|e| e.len() as i32), (5, ValueSource::Recovered));
+    /// ```
+    pub fn resolve<F: FnOnce(E) -> T>(self, on_err: F) -> (T, ValueSource) {
+        match self {
+            AdHocResult::Ok(v) => (v, ValueSource::Clean),
+            AdHocResult::AdHoc(v, _) => (v, ValueSource::Recommended),
+            AdHocResult::Err(e) => (on_err(e), ValueSource::Recovered),
+        }
+    }
+
+    /// Converts a borrowed `AdHocResult<T, E>` into an owned `Result<T, E>` treating `AdHoc` as an error.
+    ///
+    /// This is the strict conversion of `to_result`, applied to a clone of the payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let adhoc: AdHocResult<String, String> = AdHocResult::AdHoc("x".to_string(), "Not perfect".to_string());
+    /// let owned = adhoc.to_result_cloned();
+    /// assert_eq!(owned, Err("Not perfect".to_string()));
+    /// assert_eq!(owned, adhoc.to_result());
+    /// ```
+    pub fn to_result_cloned(&self) -> Result<T, E>
+    where
+        T: Clone,
+        E: Clone,
+    {
+        match self {
+            AdHocResult::Ok(v) => Ok(v.clone()),
+            AdHocResult::AdHoc(_, e) => Err(e.clone()),
+            AdHocResult::Err(e) => Err(e.clone()),
+        }
+    }
+
+    /// Converts a borrowed `AdHocResult<T, E>` into an owned `Result<T, E>` treating `AdHoc` as success.
+    ///
+    /// This is the lenient conversion of `to_result_with_adhoc`, applied to a clone of the payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let adhoc: AdHocResult<String, String> = AdHocResult::AdHoc("x".to_string(), "Not perfect".to_string());
+    /// let owned = adhoc.to_result_with_adhoc_cloned();
+    /// assert_eq!(owned, Ok("x".to_string()));
+    /// assert_eq!(owned, adhoc.to_result_with_adhoc());
+    /// ```
+    pub fn to_result_with_adhoc_cloned(&self) -> Result<T, E>
+    where
+        T: Clone,
+        E: Clone,
+    {
+        match self {
+            AdHocResult::Ok(v) => Ok(v.clone()),
+            AdHocResult::AdHoc(v, _) => Ok(v.clone()),
+            AdHocResult::Err(e) => Err(e.clone()),
+        }
+    }
+
+    /// Demotes an `Ok` to an `AdHoc` if it needed more than `max_clean` iterations.
+    ///
+    /// The iteration count is not stored in the result, so the caller supplies
+    /// `iterations_used`. An `Ok(v)` with `iterations_used > max_clean` becomes
+    /// `AdHoc(v, warning)`; otherwise it stays `Ok`. `AdHoc` and `Err` are returned
+    /// unchanged and `warning` is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let fast: AdHocResult<f64, &str> = AdHocResult::Ok(1.0);
+    /// assert!(matches!(fast.degrade_after(10, 50, "Slow convergence"), AdHocResult::Ok(1.0)));
+    ///
+    /// let slow: AdHocResult<f64, &str> = AdHocResult::Ok(1.0);
+    /// assert!(matches!(
+    ///     slow.degrade_after(80, 50, "Slow convergence"),
+    ///     AdHocResult::AdHoc(1.0, "Slow convergence")
+    /// ));
+    /// ```
+    pub fn degrade_after(self, iterations_used: usize, max_clean: usize, warning: E) -> Self {
+        match self {
+            AdHocResult::Ok(v) if iterations_used > max_clean => AdHocResult::AdHoc(v, warning),
+            other => other,
+        }
+    }
+
+    /// Converts the `AdHocResult<T, E>` into a `Result<T, E>`, letting the caller decide each `AdHoc`.
+    ///
+    /// `Ok` becomes `Ok` and `Err` becomes `Err`. For `AdHoc(v, e)`, the outcome is
+    /// whatever `on_adhoc(v, e)` returns, so the recommended value can be accepted
+    /// or rejected per call. `to_result` and `to_result_with_adhoc` are the special
+    /// cases that always reject or always accept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let accept_large = |v: f64, e| if v >= 0.9 { Ok(v) } else { Err(e) };
+    ///
+    /// let close: AdHocResult<f64, &str> = AdHocResult::AdHoc(0.95, "Low precision");
+    /// assert_eq!(close.to_result_or_adapt(accept_large), Ok(0.95));
+    ///
+    /// let far: AdHocResult<f64, &str> = AdHocResult::AdHoc(0.5, "Low precision");
+    /// assert_eq!(far.to_result_or_adapt(accept_large), Err("Low precision"));
+    ///
+    /// let err: AdHocResult<f64, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(err.to_result_or_adapt(accept_large), Err("Error"));
+    /// ```
+    pub fn to_result_or_adapt<F: FnOnce(T, E) -> Result<T, E>>(self, on_adhoc: F) -> Result<T, E> {
+        match self {
+            AdHocResult::Ok(v) => Ok(v),
+            AdHocResult::AdHoc(v, e) => on_adhoc(v, e),
+            AdHocResult::Err(e) => Err(e),
+        }
+    }
+
+    /// Replaces the result in place with `f` applied to its current contents.
+    ///
+    /// The current result is moved out, passed to `f`, and the returned result is
+    /// stored back, without requiring `T` or `E` to implement `Clone` or `Default`.
+    ///
+    /// # Panics
+    ///
+    /// While `f` runs, `self` holds no valid result. If `f` panics there is nothing
+    /// that could safely be left behind, so the process is aborted instead of unwinding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let mut x: AdHocResult<u32, &str> = AdHocResult::AdHoc(2, "Not ideal");
+    /// x.update(|r| match r {
+    ///     AdHocResult::AdHoc(v, _) => AdHocResult::Ok(v * 2),
+    ///     other => other,
+    /// });
+    /// assert!(matches!(x, AdHocResult::Ok(4)));
+    /// ```
+    pub fn update<F: FnOnce(Self) -> Self>(&mut self, f: F) {
+        struct AbortOnUnwind;
+
+        impl Drop for AbortOnUnwind {
+            fn drop(&mut self) {
+                std::process::abort();
+            }
+        }
+
+        // SAFETY: the value read out of `self` is always written back before
+        // returning, and a panic in `f` aborts before `self` can be observed.
+        unsafe {
+            let current = std::ptr::read(self);
+            let guard = AbortOnUnwind;
+            let next = f(current);
+            std::mem::forget(guard);
+            std::ptr::write(self, next);
+        }
+    }
+
+    /// Pairs the error of an `AdHoc` or `Err` with a retryability flag.
+    ///
+    /// `classify(&e)` decides whether retrying might help. The recommended value of
+    /// an `AdHoc` is kept, and `Ok` is returned unchanged without calling `classify`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let is_transient = |e: &&str| e.contains("timeout");
+    ///
+    /// let transient: AdHocResult<i32, &str> = AdHocResult::Err("Connection timeout");
+    /// assert!(matches!(transient.with_retryable(is_transient), AdHocResult::Err((_, true))));
+    ///
+    /// let permanent: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Invalid input");
+    /// assert!(matches!(permanent.with_retryable(is_transient), AdHocResult::AdHoc(42, (_, false))));
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert!(matches!(ok.with_retryable(is_transient), AdHocResult::Ok(42)));
+    /// ```
+    pub fn with_retryable<F: FnOnce(&E) -> bool>(self, classify: F) -> AdHocResult<T, (E, bool)> {
+        match self {
+            AdHocResult::Ok(v) => AdHocResult::Ok(v),
+            AdHocResult::AdHoc(v, e) => {
+                let retryable = classify(&e);
+                AdHocResult::AdHoc(v, (e, retryable))
+            }
+            AdHocResult::Err(e) => {
+                let retryable = classify(&e);
+                AdHocResult::Err((e, retryable))
+            }
+        }
+    }
+
+    /// Returns `true` if the result is an `Ok`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert!(ok.has_clean_value());
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert!(!adhoc.has_clean_value());
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert!(!err.has_clean_value());
+    /// ```
+    pub fn has_clean_value(&self) -> bool {
+        self.is_ok()
+    }
+
+    /// Returns `true` if the result is an `Ok` or `AdHoc`, i.e. it carries a usable value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert!(ok.has_any_value());
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert!(adhoc.has_any_value());
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert!(!err.has_any_value());
+    /// ```
+    pub fn has_any_value(&self) -> bool {
+        !self.is_err()
+    }
+
+    /// Consumes the result, returning `true` if it carried a usable value.
+    ///
+    /// This is the consuming form of `has_any_value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let results: Vec<AdHocResult<i32, &str>> = vec![
+    ///     AdHocResult::Ok(1),
+    ///     AdHocResult::AdHoc(2, "Not ideal"),
+    ///     AdHocResult::Err("Error"),
+    /// ];
+    /// let usable: Vec<bool> = results.into_iter().map(AdHocResult::into_bool).collect();
+    /// assert_eq!(usable, [true, true, false]);
+    /// ```
+    pub fn into_bool(self) -> bool {
+        self.has_any_value()
+    }
+
+    /// Returns the error of an `AdHoc` or `Err`, or synthesizes one for an `Ok`.
+    ///
+    /// For an `Ok`, the value is discarded and `on_ok()` provides the error, such as
+    /// a "no error" sentinel. This is useful when every outcome is funneled into a sink expecting an `E`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert_eq!(ok.error_or(|| "No error"), "No error");
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert_eq!(adhoc.error_or(|| "No error"), "Not perfect");
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(err.error_or(|| "No error"), "Error");
+    /// ```
+    pub fn error_or<F: FnOnce() -> E>(self, on_ok: F) -> E {
+        match self {
+            AdHocResult::Ok(_) => on_ok(),
+            AdHocResult::AdHoc(_, e) => e,
+            AdHocResult::Err(e) => e,
+        }
+    }
+
+    /// Combines two results by applying `f` to their values.
+    ///
+    /// The precedence is:
+    ///
+    /// * If either side is an `Err`, the result is `Err`. When both are, the error of `self` wins.
+    /// * Otherwise, if either side is an `AdHoc`, the result is `AdHoc(f(t, u), warning)`.
+    ///   When both are, the warning of `self` wins and the other is dropped.
+    /// * Otherwise, the result is `Ok(f(t, u))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let add = |a: i32, b: i32| a + b;
+    /// let ok = |v| AdHocResult::<i32, &str>::Ok(v);
+    /// let adhoc = |v, e| AdHocResult::<i32, &str>::AdHoc(v, e);
+    /// let err = |e| AdHocResult::<i32, &str>::Err(e);
+    ///
+    /// assert!(matches!(ok(1).zip_with(ok(2), add), AdHocResult::Ok(3)));
+    /// assert!(matches!(ok(1).zip_with(adhoc(2, "b"), add), AdHocResult::AdHoc(3, "b")));
+    /// assert!(matches!(ok(1).zip_with(err("b"), add), AdHocResult::Err("b")));
+    ///
+    /// assert!(matches!(adhoc(1, "a").zip_with(ok(2), add), AdHocResult::AdHoc(3, "a")));
+    /// assert!(matches!(adhoc(1, "a").zip_with(adhoc(2, "b"), add), AdHocResult::AdHoc(3, "a")));
+    /// assert!(matches!(adhoc(1, "a").zip_with(err("b"), add), AdHocResult::Err("b")));
+    ///
+    /// assert!(matches!(err("a").zip_with(ok(2), add), AdHocResult::Err("a")));
+    /// assert!(matches!(err("a").zip_with(adhoc(2, "b"), add), AdHocResult::Err("a")));
+    /// assert!(matches!(err("a").zip_with(err("b"), add), AdHocResult::Err("a")));
+    /// ```
+    pub fn zip_with<U, R, F: FnOnce(T, U) -> R>(self, other: AdHocResult<U, E>, f: F) -> AdHocResult<R, E> {
+        match (self, other) {
+            (AdHocResult::Err(e), _) => AdHocResult::Err(e),
+            (_, AdHocResult::Err(e)) => AdHocResult::Err(e),
+            (AdHocResult::Ok(t), AdHocResult::Ok(u)) => AdHocResult::Ok(f(t, u)),
+            (AdHocResult::AdHoc(t, e), AdHocResult::Ok(u)) => AdHocResult::AdHoc(f(t, u), e),
+            (AdHocResult::AdHoc(t, e), AdHocResult::AdHoc(u, _)) => AdHocResult::AdHoc(f(t, u), e),
+            (AdHocResult::Ok(t), AdHocResult::AdHoc(u, e)) => AdHocResult::AdHoc(f(t, u), e),
+        }
+    }
+
+    /// Converts the `AdHocResult<T, E>` into a `Result` carrying the value and an optional warning.
+    ///
+    /// `Ok(v)` becomes `Ok((v, None))` and `AdHoc(v, e)` becomes `Ok((v, Some(e)))`,
+    /// so a value is returned whenever one exists. Only `Err(e)` becomes `Err(e)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert_eq!(ok.value_with_warning(), Ok((42, None)));
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert_eq!(adhoc.value_with_warning(), Ok((42, Some("Not perfect"))));
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(err.value_with_warning(), Err("Error"));
+    /// ```
+    pub fn value_with_warning(self) -> Result<(T, Option<E>), E> {
+        match self {
+            AdHocResult::Ok(v) => Ok((v, None)),
+            AdHocResult::AdHoc(v, e) => Ok((v, Some(e))),
+            AdHocResult::Err(e) => Err(e),
+        }
+    }
+
+    /// Promotes an `AdHoc` whose error is effectively empty to an `Ok`.
+    ///
+    /// `AdHoc(v, e)` becomes `Ok(v)` when `is_empty(&e)` holds, and is kept otherwise.
+    /// `Ok` and `Err` are returned unchanged without calling `is_empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let no_warnings: AdHocResult<i32, Vec<&str>> = AdHocResult::AdHoc(42, vec![]);
+    /// assert!(matches!(no_warnings.coalesce_empty(Vec::is_empty), AdHocResult::Ok(42)));
+    ///
+    /// let warnings: AdHocResult<i32, Vec<&str>> = AdHocResult::AdHoc(42, vec!["Not perfect"]);
+    /// assert!(matches!(warnings.coalesce_empty(Vec::is_empty), AdHocResult::AdHoc(42, _)));
+    ///
+    /// let blank: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "");
+    /// assert!(matches!(blank.coalesce_empty(|e| e.is_empty()), AdHocResult::Ok(42)));
+    /// ```
+    pub fn coalesce_empty(self, is_empty: impl FnOnce(&E) -> bool) -> Self {
+        match self {
+            AdHocResult::AdHoc(v, e) if is_empty(&e) => AdHocResult::Ok(v),
+            other => other,
+        }
+    }
+
+    /// Transforms the value of an `Ok` or `AdHoc` with `f`, keeping the error of an `AdHoc`.
+    ///
+    /// This is an alias of `map`, named to pair with `on_err` and `on_adhoc`.
+    /// `Err` is passed through unchanged and `f` is not called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(2, "Not ideal");
+    /// assert!(matches!(adhoc.on_ok(|v| v * 10), AdHocResult::AdHoc(20, "Not ideal")));
+    /// ```
+    pub fn on_ok<U, F: FnOnce(T) -> U>(self, f: F) -> AdHocResult<U, E> {
+        self.map(f)
+    }
+
+    /// Calls `f` with the error of an `Err` and returns `self` unchanged.
+    ///
+    /// Only hard errors are visited: `f` is not called for the error of an `AdHoc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let mut seen = Vec::new();
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(2, "Not ideal");
+    /// let adhoc = adhoc.on_err(|e| seen.push(*e));
+    /// assert!(matches!(adhoc, AdHocResult::AdHoc(2, "Not ideal")));
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// let err = err.on_err(|e| seen.push(*e));
+    /// assert!(matches!(err, AdHocResult::Err("Error")));
+    ///
+    /// assert_eq!(seen, ["Error"]);
+    /// ```
+    pub fn on_err<F: FnOnce(&E)>(self, f: F) -> Self {
+        if let AdHocResult::Err(e) = &self {
+            f(e);
+        }
+        self
+    }
+
+    /// Calls `f` with the value and error of an `AdHoc` and returns `self` unchanged.
+    ///
+    /// `f` is not called for `Ok` or `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let mut seen = Vec::new();
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(2, "Not ideal");
+    /// let adhoc = adhoc.on_adhoc(|v, e| seen.push((*v, *e)));
+    /// assert!(matches!(adhoc, AdHocResult::AdHoc(2, "Not ideal")));
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// let err = err.on_adhoc(|v, e| seen.push((*v, *e)));
+    /// assert!(matches!(err, AdHocResult::Err("Error")));
+    ///
+    /// assert_eq!(seen, [(2, "Not ideal")]);
+    /// ```
+    pub fn on_adhoc<F: FnOnce(&T, &E)>(self, f: F) -> Self {
+        if let AdHocResult::AdHoc(v, e) = &self {
+            f(v, e);
+        }
+        self
+    }
+
+    /// Replaces `self` with `candidate` if the candidate's value is better.
+    ///
+    /// `better(current, candidate)` should return `true` when `candidate` improves on
+    /// `current`. When both results carry a value (`Ok` or `AdHoc`):
+    ///
+    /// * `self` is replaced if `better(current, candidate)` is `true`;
+    /// * on a tie, where neither value is better than the other, a clean `Ok`
+    ///   candidate replaces an `AdHoc` in `self`;
+    /// * otherwise `self` is kept.
+    ///
+    /// When either side is an `Err`, the value-bearing one is kept: an `Err` candidate
+    /// never replaces `self`, and an `Err` in `self` is replaced by any candidate
+    /// carrying a value. `better` may be called twice to detect ties.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let lower_residual = |current: &f64, candidate: &f64| candidate < current;
+    ///
+    /// let mut best: AdHocResult<f64, &str> = AdHocResult::AdHoc(0.5, "Not converged");
+    ///
+    /// best.update_if_better(AdHocResult::Ok(0.1), lower_residual);
+    /// assert!(matches!(best, AdHocResult::Ok(0.1)));
+    ///
+    /// best.update_if_better(AdHocResult::AdHoc(0.3, "Not converged"), lower_residual);
+    /// assert!(matches!(best, AdHocResult::Ok(0.1)));
+    ///
+    /// best.update_if_better(AdHocResult::Err("Diverged"), lower_residual);
+    /// assert!(matches!(best, AdHocResult::Ok(0.1)));
+    ///
+    /// // On a tie, a clean candidate is preferred over a degraded one
+    /// let mut tied: AdHocResult<f64, &str> = AdHocResult::AdHoc(0.2, "Not converged");
+    /// tied.update_if_better(AdHocResult::Ok(0.2), lower_residual);
+    /// assert!(matches!(tied, AdHocResult::Ok(0.2)));
+    /// ```
+    pub fn update_if_better<F: FnMut(&T, &T) -> bool>(&mut self, candidate: Self, mut better: F) {
+        let replace = match (&*self, &candidate) {
+            (_, AdHocResult::Err(_)) => false,
+            (AdHocResult::Err(_), _) => true,
+            (
+                AdHocResult::Ok(current) | AdHocResult::AdHoc(current, _),
+                AdHocResult::Ok(new) | AdHocResult::AdHoc(new, _),
+            ) => {
+                better(current, new)
+                    || (matches!(self, AdHocResult::AdHoc(..))
+                        && matches!(candidate, AdHocResult::Ok(_))
+                        && !better(new, current))
+            }
+        };
+        if replace {
+            *self = candidate;
+        }
+    }
+
+    /// Builds a structured error from the string form of the error of an `AdHoc` or `Err`.
+    ///
+    /// The `E: AsRef<str>` bound covers string errors such as `String` and `&str`,
+    /// whose text is passed to `f` to produce the new error. The recommended value
+    /// of an `AdHoc` is kept, and `Ok` is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum SolveError {
+    ///     Singular,
+    ///     Other(String),
+    /// }
+    ///
+    /// let classify = |msg: &str| {
+    ///     if msg.contains("singular") { SolveError::Singular } else { SolveError::Other(msg.to_string()) }
+    /// };
+    ///
+    /// let adhoc: AdHocResult<f64, String> = AdHocResult::AdHoc(1.0, "matrix is singular".to_string());
+    /// assert!(matches!(adhoc.classify_err(classify), AdHocResult::AdHoc(1.0, SolveError::Singular)));
+    ///
+    /// let err: AdHocResult<f64, &str> = AdHocResult::Err("out of memory");
+    /// assert!(matches!(err.classify_err(classify), AdHocResult::Err(SolveError::Other(m)) if m == "out of memory"));
+    /// ```
+    pub fn classify_err<E2, F: FnOnce(&str) -> E2>(self, f: F) -> AdHocResult<T, E2>
+    where
+        E: AsRef<str>,
+    {
+        match self {
+            AdHocResult::Ok(v) => AdHocResult::Ok(v),
+            AdHocResult::AdHoc(v, e) => AdHocResult::AdHoc(v, f(e.as_ref())),
+            AdHocResult::Err(e) => AdHocResult::Err(f(e.as_ref())),
+        }
+    }
+
+    /// Swaps the roles of the value and the error.
+    ///
+    /// `Ok(v)` becomes `Err(v)` and `Err(e)` becomes `Ok(e)`. An `AdHoc(v, e)` keeps
+    /// its variant with the payloads exchanged, becoming `AdHoc(e, v)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert!(matches!(ok.swap(), AdHocResult::Err(42)));
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert!(matches!(adhoc.swap(), AdHocResult::AdHoc("Not perfect", 42)));
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert!(matches!(err.swap(), AdHocResult::Ok("Error")));
+    /// ```
+    pub fn swap(self) -> AdHocResult<E, T> {
+        match self {
+            AdHocResult::Ok(v) => AdHocResult::Err(v),
+            AdHocResult::AdHoc(v, e) => AdHocResult::AdHoc(e, v),
+            AdHocResult::Err(e) => AdHocResult::Ok(e),
+        }
+    }
+
+    /// Transforms the error of an `AdHoc` or `Err` with an async closure.
+    ///
+    /// The future returned by `op(e)` is awaited, and the recommended value of an
+    /// `AdHoc` is kept. For `Ok`, `op` is not called and nothing is awaited.
+    ///
+    /// Requires the `futures` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// async fn lookup_context(e: &str) -> String {
+    ///     format!("{e} (job 17)")
+    /// }
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// let enriched = futures::executor::block_on(adhoc.map_err_async(lookup_context));
+    /// assert!(matches!(enriched, AdHocResult::AdHoc(42, e) if e == "Not perfect (job 17)"));
+    /// ```
+    #[cfg(feature = "futures")]
+    pub async fn map_err_async<F, Fut, E2>(self, op: F) -> AdHocResult<T, E2>
+    where
+        F: FnOnce(E) -> Fut,
+        Fut: std::future::Future<Output = E2>,
+    {
+        match self {
+            AdHocResult::Ok(v) => AdHocResult::Ok(v),
+            AdHocResult::AdHoc(v, e) => AdHocResult::AdHoc(v, op(e).await),
+            AdHocResult::Err(e) => AdHocResult::Err(op(e).await),
+        }
+    }
+
+    /// Unwraps a result, yielding the content of an `Ok`, with a detailed panic message otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is an `AdHoc` or `Err`, with a message containing the
+    /// variant and the `Debug` output of its payloads. The panic location is that
+    /// of the caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<u32, &str> = AdHocResult::Ok(2);
+    /// assert_eq!(ok.unwrap_clean(), 2);
+    ///
+    /// let adhoc: AdHocResult<u32, &str> = AdHocResult::AdHoc(2, "Not ideal");
+    /// let payload = std::panic::catch_unwind(|| adhoc.unwrap_clean()).unwrap_err();
+    /// assert_eq!(
+    ///     payload.downcast_ref::<String>().unwrap(),
+    ///     r#"called `unwrap_clean` on a result that is not clean: AdHoc(2, "Not ideal")"#
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn unwrap_clean(self) -> T
+    where
+        T: std::fmt::Debug,
+        E: std::fmt::Debug,
+    {
+        match self {
+            AdHocResult::Ok(v) => v,
+            other => panic!("called `unwrap_clean` on a result that is not clean: {other:?}"),
+        }
+    }
+
+    /// Transforms the error with access to the recommended value of an `AdHoc`.
+    ///
+    /// Two closures are needed because only an `AdHoc` has a value to refer to:
+    /// for `AdHoc(v, e)` the error becomes `f(&v, e)` and the value is kept, while
+    /// for `Err(e)` the error becomes `g(e)`. `Ok` is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let describe = |v: &f64, e: &str| format!("{e}; recommending {v}");
+    /// let plain = |e: &str| e.to_string();
+    ///
+    /// let adhoc: AdHocResult<f64, &str> = AdHocResult::AdHoc(1.5, "Low precision");
+    /// let reported = adhoc.fold_value_into_err(describe, plain);
+    /// assert!(matches!(reported, AdHocResult::AdHoc(1.5, e) if e == "Low precision; recommending 1.5"));
+    ///
+    /// let err: AdHocResult<f64, &str> = AdHocResult::Err("Singular");
+    /// let reported = err.fold_value_into_err(describe, plain);
+    /// assert!(matches!(reported, AdHocResult::Err(e) if e == "Singular"));
+    /// ```
+    pub fn fold_value_into_err<E2, F, G>(self, f: F, g: G) -> AdHocResult<T, E2>
+    where
+        F: FnOnce(&T, E) -> E2,
+        G: FnOnce(E) -> E2,
+    {
+        match self {
+            AdHocResult::Ok(v) => AdHocResult::Ok(v),
+            AdHocResult::AdHoc(v, e) => {
+                let e2 = f(&v, e);
+                AdHocResult::AdHoc(v, e2)
+            }
+            AdHocResult::Err(e) => AdHocResult::Err(g(e)),
+        }
+    }
+
+    /// Transforms both the value and the error with async closures.
+    ///
+    /// Only the futures for the payloads present are created and awaited: `Ok` awaits
+    /// `vf(v)`, `Err` awaits `ef(e)`, and `AdHoc` awaits `vf(v)` and then `ef(e)`.
+    ///
+    /// Requires the `futures` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// async fn fetch_units(v: f64) -> String {
+    ///     format!("{v} m")
+    /// }
+    ///
+    /// async fn lookup_context(e: &str) -> String {
+    ///     format!("{e} (job 17)")
+    /// }
+    ///
+    /// let adhoc: AdHocResult<f64, &str> = AdHocResult::AdHoc(1.5, "Not perfect");
+    /// let mapped = futures::executor::block_on(adhoc.map_both_async(fetch_units, lookup_context));
+    /// assert!(matches!(mapped, AdHocResult::AdHoc(v, e) if v == "1.5 m" && e == "Not perfect (job 17)"));
+    /// ```
+    #[cfg(feature = "futures")]
+    pub async fn map_both_async<U, F, VF, VFut, EF, EFut>(self, vf: VF, ef: EF) -> AdHocResult<U, F>
+    where
+        VF: FnOnce(T) -> VFut,
+        VFut: std::future::Future<Output = U>,
+        EF: FnOnce(E) -> EFut,
+        EFut: std::future::Future<Output = F>,
+    {
+        match self {
+            AdHocResult::Ok(v) => AdHocResult::Ok(vf(v).await),
+            AdHocResult::AdHoc(v, e) => {
+                let u = vf(v).await;
+                AdHocResult::AdHoc(u, ef(e).await)
+            }
+            AdHocResult::Err(e) => AdHocResult::Err(ef(e).await),
+        }
+    }
+
+    /// Converts the error of an `AdHoc` or `Err` into `F` using `From`.
+    ///
+    /// This widens the error type the way `?` does for `Result`, keeping the
+    /// recommended value of an `AdHoc`. `Ok` is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    /// use std::error::Error;
+    ///
+    /// #[derive(Debug)]
+    /// enum AppError {
+    ///     Parse(std::num::ParseIntError),
+    /// }
+    ///
+    /// impl From<std::num::ParseIntError> for AppError {
+    ///     fn from(e: std::num::ParseIntError) -> Self {
+    ///         AppError::Parse(e)
+    ///     }
+    /// }
+    ///
+    /// let parse_error = "x".parse::<i32>().unwrap_err();
+    ///
+    /// let adhoc: AdHocResult<i32, std::num::ParseIntError> = AdHocResult::AdHoc(0, parse_error.clone());
+    /// assert!(matches!(adhoc.coerce_err::<AppError>(), AdHocResult::AdHoc(0, AppError::Parse(_))));
+    ///
+    /// let err: AdHocResult<i32, std::num::ParseIntError> = AdHocResult::Err(parse_error);
+    /// let boxed: AdHocResult<i32, Box<dyn Error>> = err.coerce_err();
+    /// assert!(matches!(boxed, AdHocResult::Err(e) if e.to_string() == "invalid digit found in string"));
+    /// ```
+    pub fn coerce_err<F: From<E>>(self) -> AdHocResult<T, F> {
+        match self {
+            AdHocResult::Ok(v) => AdHocResult::Ok(v),
+            AdHocResult::AdHoc(v, e) => AdHocResult::AdHoc(v, F::from(e)),
+            AdHocResult::Err(e) => AdHocResult::Err(F::from(e)),
+        }
+    }
+
+    /// Splits the result into a `Result` treating `AdHoc` as success and the warning of an `AdHoc`.
+    ///
+    /// `Ok(v)` gives `(Ok(v), None)` and `AdHoc(v, e)` gives `(Ok(v), Some(e))`.
+    /// A hard error is carried only by the `Result`: `Err(e)` gives `(Err(e), None)`,
+    /// so the second element is a warning and never a duplicate of the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert_eq!(ok.explode(), (Ok(42), None));
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert_eq!(adhoc.explode(), (Ok(42), Some("Not perfect")));
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(err.explode(), (Err("Error"), None));
+    /// ```
+    pub fn explode(self) -> (Result<T, E>, Option<E>) {
+        match self {
+            AdHocResult::Ok(v) => (Ok(v), None),
+            AdHocResult::AdHoc(v, e) => (Ok(v), Some(e)),
+            AdHocResult::Err(e) => (Err(e), None),
+        }
+    }
+
+    /// Pairs the error of an `AdHoc` or `Err` with the time it was observed.
+    ///
+    /// The timestamp is taken from `SystemTime::now()` when this method is called.
+    /// Being a wall-clock time, it is not guaranteed to be monotonic. The recommended
+    /// value of an `AdHoc` is kept, and `Ok` is returned unchanged without reading the clock.
+    ///
+    /// Requires the `std` feature, which is enabled by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    /// use std::time::SystemTime;
+    ///
+    /// let before = SystemTime::now();
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// let stamped = err.timestamp_err();
+    /// let after = SystemTime::now();
+    ///
+    /// assert!(matches!(stamped, AdHocResult::Err(("Error", t)) if before <= t && t <= after));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn timestamp_err(self) -> AdHocResult<T, (E, std::time::SystemTime)> {
+        match self {
+            AdHocResult::Ok(v) => AdHocResult::Ok(v),
+            AdHocResult::AdHoc(v, e) => AdHocResult::AdHoc(v, (e, std::time::SystemTime::now())),
+            AdHocResult::Err(e) => AdHocResult::Err((e, std::time::SystemTime::now())),
+        }
+    }
+
+    /// Returns the variant of the result as a `ResultKind`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::{AdHocResult, ResultKind};
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert_eq!(adhoc.kind(), ResultKind::AdHoc);
+    /// ```
+    pub fn kind(&self) -> ResultKind {
+        match self {
+            AdHocResult::Ok(_) => ResultKind::Ok,
+            AdHocResult::AdHoc(_, _) => ResultKind::AdHoc,
+            AdHocResult::Err(_) => ResultKind::Err,
+        }
+    }
+
+    /// Maps an `AdHocResult<T, E>` to `AdHocResult<U, E>` by applying a function to the value.
+    ///
+    /// The function is applied to the value of an `Ok` and to the recommended value of
+    /// an `AdHoc`, whose error is kept intact. `Err` is passed through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(2);
+    /// assert!(matches!(ok.map(|v| v * 10), AdHocResult::Ok(20)));
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(2, "Not ideal");
+    /// assert!(matches!(adhoc.map(|v| v * 10), AdHocResult::AdHoc(20, "Not ideal")));
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert!(matches!(err.map(|v| v * 10), AdHocResult::Err("Error")));
+    /// ```
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> AdHocResult<U, E> {
+        match self {
+            AdHocResult::Ok(v) => AdHocResult::Ok(f(v)),
+            AdHocResult::AdHoc(v, e) => AdHocResult::AdHoc(f(v), e),
+            AdHocResult::Err(e) => AdHocResult::Err(e),
+        }
+    }
+
+    /// Maps an `AdHocResult<T, E>` to `AdHocResult<T, F>` by applying a function to the error.
+    ///
+    /// The function is applied to the error of both `AdHoc` and `Err`, and the
+    /// recommended value of an `AdHoc` is kept intact. `Ok` is passed through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(2);
+    /// assert!(matches!(ok.map_err(str::len), AdHocResult::Ok(2)));
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(2, "Not ideal");
+    /// assert!(matches!(adhoc.map_err(str::len), AdHocResult::AdHoc(2, 9)));
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert!(matches!(err.map_err(str::len), AdHocResult::Err(5)));
+    /// ```
+    pub fn map_err<F, O: FnOnce(E) -> F>(self, op: O) -> AdHocResult<T, F> {
+        match self {
+            AdHocResult::Ok(v) => AdHocResult::Ok(v),
+            AdHocResult::AdHoc(v, e) => AdHocResult::AdHoc(v, op(e)),
+            AdHocResult::Err(e) => AdHocResult::Err(op(e)),
+        }
+    }
+
+    /// Rewrites the error of an `AdHoc` by applying a function to it.
+    ///
+    /// The recommended value is kept. `Ok` and `Err` are passed through unchanged,
+    /// so the error of an `Err` is not touched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let adhoc: AdHocResult<i32, String> = AdHocResult::AdHoc(2, "Not ideal".to_string());
+    /// let adhoc = adhoc.map_adhoc(|e| format!("step 1: {e}"));
+    /// assert!(matches!(adhoc, AdHocResult::AdHoc(2, e) if e == "step 1: Not ideal"));
+    ///
+    /// let err: AdHocResult<i32, String> = AdHocResult::Err("Error".to_string());
+    /// let err = err.map_adhoc(|e| format!("step 1: {e}"));
+    /// assert!(matches!(err, AdHocResult::Err(e) if e == "Error"));
+    /// ```
+    pub fn map_adhoc<F: FnOnce(E) -> E>(self, f: F) -> Self {
+        match self {
+            AdHocResult::AdHoc(v, e) => AdHocResult::AdHoc(v, f(e)),
+            other => other,
+        }
+    }
+
+    /// Converts the `AdHocResult<T, E>` into a `Result<T, E>`, dropping the recommendation of an `AdHoc`.
+    ///
+    /// This is an alias of `to_result`, named so that the discarded value is obvious at the call site.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert_eq!(adhoc.into_result_dropping_recommendation(), Err("Not perfect"));
+    ///
+    /// for x in [AdHocResult::Ok(42), AdHocResult::AdHoc(42, "Not perfect"), AdHocResult::Err("Error")] {
+    ///     let y = x.clone();
+    ///     assert_eq!(x.into_result_dropping_recommendation(), y.to_result());
+    /// }
+    /// ```
+    pub fn into_result_dropping_recommendation(self) -> Result<T, E> {
+        self.to_result()
+    }
+
+    /// Converts the `AdHocResult<T, E>` into a `Result<T, E>`, keeping the recommendation of an `AdHoc`.
+    ///
+    /// This is an alias of `to_result_with_adhoc`, named so that the accepted value is obvious at the call site.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert_eq!(adhoc.into_result_keeping_recommendation(), Ok(42));
+    ///
+    /// for x in [AdHocResult::Ok(42), AdHocResult::AdHoc(42, "Not perfect"), AdHocResult::Err("Error")] {
+    ///     let y = x.clone();
+    ///     assert_eq!(x.into_result_keeping_recommendation(), y.to_result_with_adhoc());
+    /// }
+    /// ```
+    pub fn into_result_keeping_recommendation(self) -> Result<T, E> {
+        self.to_result_with_adhoc()
+    }
+
+    /// Chains a computation returning `AdHocResult` onto the value of an `Ok` or `AdHoc`.
+    ///
+    /// `f` runs on the value of an `Ok` and on the recommended value of an `AdHoc`.
+    /// A degradation upstream is never forgotten:
+    ///
+    /// * After an `Ok`, the result is whatever `f` returns.
+    /// * After an `AdHoc(_, e1)`, an `Ok(u)` from `f` becomes `AdHoc(u, e1)`, an
+    ///   `AdHoc(u, e2)` from `f` is kept as-is so the downstream error `e2` survives,
+    ///   and an `Err(e2)` from `f` is kept as-is.
+    /// * An `Err` short-circuits without calling `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok = |v: i32| AdHocResult::<i32, &str>::Ok(v + 1);
+    /// let adhoc = |v: i32| AdHocResult::<i32, &str>::AdHoc(v + 1, "e2");
+    /// let err = |_: i32| AdHocResult::<i32, &str>::Err("e2");
+    ///
+    /// let up_ok = || AdHocResult::<i32, &str>::Ok(1);
+    /// assert!(matches!(up_ok().and_then(ok), AdHocResult::Ok(2)));
+    /// assert!(matches!(up_ok().and_then(adhoc), AdHocResult::AdHoc(2, "e2")));
+    /// assert!(matches!(up_ok().and_then(err), AdHocResult::Err("e2")));
+    ///
+    /// let up_adhoc = || AdHocResult::<i32, &str>::AdHoc(1, "e1");
+    /// assert!(matches!(up_adhoc().and_then(ok), AdHocResult::AdHoc(2, "e1")));
+    /// assert!(matches!(up_adhoc().and_then(adhoc), AdHocResult::AdHoc(2, "e2")));
+    /// assert!(matches!(up_adhoc().and_then(err), AdHocResult::Err("e2")));
+    ///
+    /// let up_err = || AdHocResult::<i32, &str>::Err("e1");
+    /// assert!(matches!(up_err().and_then(ok), AdHocResult::Err("e1")));
+    /// assert!(matches!(up_err().and_then(adhoc), AdHocResult::Err("e1")));
+    /// assert!(matches!(up_err().and_then(err), AdHocResult::Err("e1")));
+    /// ```
+    pub fn and_then<U, F: FnOnce(T) -> AdHocResult<U, E>>(self, f: F) -> AdHocResult<U, E> {
+        match self {
+            AdHocResult::Ok(v) => f(v),
+            AdHocResult::AdHoc(v, e1) => match f(v) {
+                AdHocResult::Ok(u) => AdHocResult::AdHoc(u, e1),
+                other => other,
+            },
+            AdHocResult::Err(e) => AdHocResult::Err(e),
+        }
+    }
+
+    /// Recomputes the result from the error of an `Err`.
+    ///
+    /// For `Err(e)`, the result is whatever `f(e)` returns, which may be any variant.
+    /// An `AdHoc` is left alone, since its recommendation is considered good enough,
+    /// and `Ok` is also returned unchanged. In neither case is `f` called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let recompute = |_| AdHocResult::AdHoc(1.0, "Recomputed with a coarse grid");
+    ///
+    /// let err: AdHocResult<f64, &str> = AdHocResult::Err("Diverged");
+    /// assert!(matches!(err.value_or_compute(recompute), AdHocResult::AdHoc(1.0, "Recomputed with a coarse grid")));
+    ///
+    /// let adhoc: AdHocResult<f64, &str> = AdHocResult::AdHoc(2.0, "Not perfect");
+    /// assert!(matches!(adhoc.value_or_compute(recompute), AdHocResult::AdHoc(2.0, "Not perfect")));
+    /// ```
+    pub fn value_or_compute<F: FnOnce(E) -> AdHocResult<T, E>>(self, f: F) -> AdHocResult<T, E> {
+        match self {
+            AdHocResult::Err(e) => f(e),
+            other => other,
+        }
+    }
+
+    /// Checks a custom invariant in debug builds and returns `self` unchanged.
+    ///
+    /// Whether the check runs is decided by the build profile of this crate, not of
+    /// the caller: if `ad_hoc_result` is built with `debug_assertions`, this asserts
+    /// that `check(&self)` holds. Otherwise `check` is not called at all, so the
+    /// method is a free passthrough.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `check(&self)` returns `false` and this crate is built with
+    /// `debug_assertions`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let finite_ok = |r: &AdHocResult<f64, &str>| !matches!(r, AdHocResult::Ok(v) if !v.is_finite());
+    ///
+    /// let ok: AdHocResult<f64, &str> = AdHocResult::Ok(1.0);
+    /// assert_eq!(ok.validated(finite_ok), AdHocResult::Ok(1.0));
+    /// ```
+    pub fn validated(self, check: impl FnOnce(&Self) -> bool) -> Self {
+        debug_assert!(check(&self), "`AdHocResult` failed validation");
+        self
+    }
+
+    /// Returns `true` if the result is an `Ok`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert!(ok.is_ok());
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert!(!adhoc.is_ok());
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert!(!err.is_ok());
+    /// ```
+    pub fn is_ok(&self) -> bool {
+        matches!(self, AdHocResult::Ok(_))
+    }
+
+    /// Returns `true` if the result is an `AdHoc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert!(!ok.is_adhoc());
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert!(adhoc.is_adhoc());
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert!(!err.is_adhoc());
+    /// ```
+    pub fn is_adhoc(&self) -> bool {
+        matches!(self, AdHocResult::AdHoc(_, _))
+    }
+
+    /// Returns `true` if the result is an `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert!(!ok.is_err());
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert!(!adhoc.is_err());
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert!(err.is_err());
+    /// ```
+    pub fn is_err(&self) -> bool {
+        matches!(self, AdHocResult::Err(_))
+    }
+
+    /// Converts the result into an `Option<T>`, keeping the value of an `Ok` or `AdHoc`.
+    ///
+    /// The recommended value of an `AdHoc` is still usable, so it is returned as `Some`
+    /// and its error is discarded. Note that `err` also returns `Some` for an `AdHoc`,
+    /// since that variant carries both a value and an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert_eq!(ok.ok(), Some(42));
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert_eq!(adhoc.ok(), Some(42));
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(err.ok(), None);
+    /// ```
+    pub fn ok(self) -> Option<T> {
+        match self {
+            AdHocResult::Ok(v) => Some(v),
+            AdHocResult::AdHoc(v, _) => Some(v),
+            AdHocResult::Err(_) => None,
+        }
+    }
+
+    /// Converts the result into an `Option<E>`, keeping the error of an `AdHoc` or `Err`.
+    ///
+    /// The recommended value of an `AdHoc` is discarded. Note that `ok` also returns
+    /// `Some` for an `AdHoc`, since that variant carries both a value and an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert_eq!(ok.err(), None);
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert_eq!(adhoc.err(), Some("Not perfect"));
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(err.err(), Some("Error"));
+    /// ```
+    pub fn err(self) -> Option<E> {
+        match self {
+            AdHocResult::Ok(_) => None,
+            AdHocResult::AdHoc(_, e) => Some(e),
+            AdHocResult::Err(e) => Some(e),
+        }
+    }
+
+    /// Converts the result into an `Option<(T, E)>`, keeping both payloads of an `AdHoc`.
+    ///
+    /// Returns `None` for `Ok` and `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert_eq!(ok.adhoc_err(), None);
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// assert_eq!(adhoc.adhoc_err(), Some((42, "Not perfect")));
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(err.adhoc_err(), None);
+    /// ```
+    pub fn adhoc_err(self) -> Option<(T, E)> {
+        match self {
+            AdHocResult::AdHoc(v, e) => Some((v, e)),
+            _ => None,
+        }
+    }
+
+    /// Converts from `&AdHocResult<T, E>` to `AdHocResult<&T, &E>`.
+    ///
+    /// Each variant maps to the same variant holding references to the payloads,
+    /// so methods can be chained without consuming the original.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let adhoc: AdHocResult<Vec<f64>, String> = AdHocResult::AdHoc(vec![1.0, 2.0], "Not ideal".to_string());
+    /// assert!(adhoc.as_ref().is_adhoc());
+    /// assert_eq!(adhoc.as_ref().map(|v| v.len()), AdHocResult::AdHoc(2, &"Not ideal".to_string()));
+    /// // The original is still available
+    /// assert_eq!(adhoc.unwrap_adhoc(), [1.0, 2.0]);
+    /// ```
+    pub fn as_ref(&self) -> AdHocResult<&T, &E> {
+        match self {
+            AdHocResult::Ok(v) => AdHocResult::Ok(v),
+            AdHocResult::AdHoc(v, e) => AdHocResult::AdHoc(v, e),
+            AdHocResult::Err(e) => AdHocResult::Err(e),
+        }
+    }
+
+    /// Converts from `&mut AdHocResult<T, E>` to `AdHocResult<&mut T, &mut E>`.
+    ///
+    /// Each variant maps to the same variant holding mutable references to the
+    /// payloads, so they can be patched in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let mut adhoc: AdHocResult<Vec<f64>, String> = AdHocResult::AdHoc(vec![1.0, 2.0], "Not ideal".to_string());
+    /// if let AdHocResult::AdHoc(v, e) = adhoc.as_mut() {
+    ///     v[0] = 0.5;
+    ///     e.push_str(", patched");
+    /// }
+    /// assert_eq!(adhoc, AdHocResult::AdHoc(vec![0.5, 2.0], "Not ideal, patched".to_string()));
+    ///
+    /// let mut ok: AdHocResult<i32, &str> = AdHocResult::Ok(1);
+    /// ok.as_mut().map(|v| *v += 1);
+    /// assert_eq!(ok, AdHocResult::Ok(2));
+    /// ```
+    pub fn as_mut(&mut self) -> AdHocResult<&mut T, &mut E> {
+        match self {
+            AdHocResult::Ok(v) => AdHocResult::Ok(v),
+            AdHocResult::AdHoc(v, e) => AdHocResult::AdHoc(v, e),
+            AdHocResult::Err(e) => AdHocResult::Err(e),
+        }
+    }
+
+    /// Converts the `AdHocResult<T, E>` into a `Result<T, E>` like `to_result`, logging dropped recommendations.
+    ///
+    /// For an `AdHoc`, a `log::debug!` record names the discarded value and the error
+    /// before `Err` is returned, so lost recommendations remain visible. Nothing is
+    /// logged for `Ok` or `Err`.
+    ///
+    /// Requires the `log` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    /// use std::sync::Mutex;
+    ///
+    /// static LINES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    ///
+    /// struct Capture;
+    ///
+    /// impl log::Log for Capture {
+    ///     fn enabled(&self, _: &log::Metadata) -> bool { true }
+    ///     fn log(&self, record: &log::Record) {
+    ///         LINES.lock().unwrap().push(format!("{} {}", record.level(), record.args()));
+    ///     }
+    ///     fn flush(&self) {}
+    /// }
+    ///
+    /// log::set_logger(&Capture).unwrap();
+    /// log::set_max_level(log::LevelFilter::Trace);
+    ///
+    /// let err: AdHocResult<f64, &str> = AdHocResult::Err("Singular");
+    /// assert_eq!(err.to_result_logging(), Err("Singular"));
+    /// assert!(LINES.lock().unwrap().is_empty());
+    ///
+    /// let adhoc: AdHocResult<f64, &str> = AdHocResult::AdHoc(1.5, "Low precision");
+    /// assert_eq!(adhoc.to_result_logging(), Err("Low precision"));
+    /// assert_eq!(*LINES.lock().unwrap(), ["DEBUG dropping recommendation 1.5 due to Low precision"]);
+    /// ```
+    #[cfg(feature = "log")]
+    pub fn to_result_logging(self) -> Result<T, E>
+    where
+        T: std::fmt::Debug,
+        E: std::fmt::Display,
+    {
+        if let AdHocResult::AdHoc(value, error) = &self {
+            log::debug!("dropping recommendation {value:?} due to {error}");
+        }
+        self.to_result()
+    }
+
+    /// Formats the result as a fixed-width row of `[value, warning, error]` columns.
+    ///
+    /// Each column holds the `Display` output of the matching payload, or `None`:
+    /// `Ok(v)` gives `[Some(v), None, None]`, `AdHoc(v, e)` gives `[Some(v), Some(e), None]`,
+    /// and `Err(e)` gives `[None, None, Some(e)]`. The error of an `AdHoc` goes in the
+    /// warning column, so the error column is only filled for hard errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<f64, &str> = AdHocResult::Ok(1.5);
+    /// assert_eq!(ok.into_row(), [Some("1.5".to_string()), None, None]);
+    ///
+    /// let adhoc: AdHocResult<f64, &str> = AdHocResult::AdHoc(1.5, "Low precision");
+    /// assert_eq!(adhoc.into_row(), [Some("1.5".to_string()), Some("Low precision".to_string()), None]);
+    ///
+    /// let err: AdHocResult<f64, &str> = AdHocResult::Err("Singular");
+    /// assert_eq!(err.into_row(), [None, None, Some("Singular".to_string())]);
+    /// ```
+    pub fn into_row(self) -> [Option<String>; 3]
+    where
+        T: std::fmt::Display,
+        E: std::fmt::Display,
+    {
+        match self {
+            AdHocResult::Ok(v) => [Some(v.to_string()), None, None],
+            AdHocResult::AdHoc(v, e) => [Some(v.to_string()), Some(e.to_string()), None],
+            AdHocResult::Err(e) => [None, None, Some(e.to_string())],
+        }
+    }
+
+    /// Bundles the result with metadata about how it was produced.
+    ///
+    /// This is equivalent to `WithMeta::new(self, meta)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let x = AdHocResult::<u32, &str>::Ok(42).with_meta("converged in 3 iterations");
+    /// assert_eq!(x.meta, "converged in 3 iterations");
+    /// ```
+    pub fn with_meta<M>(self, meta: M) -> WithMeta<T, E, M> {
+        WithMeta::new(self, meta)
+    }
+
+    /// Downgrades the result to an `AdHoc` carrying the given warning.
+    ///
+    /// An `Ok(v)` becomes `AdHoc(v, error)`, an existing `AdHoc` has its error
+    /// replaced by `error`, and an `Err` is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let x: AdHocResult<u32, &str> = AdHocResult::Ok(1);
+    /// assert_eq!(x.downgrade("lowprec"), AdHocResult::AdHoc(1, "lowprec"));
+    ///
+    /// let x: AdHocResult<u32, &str> = AdHocResult::AdHoc(1, "old");
+    /// assert_eq!(x.downgrade("lowprec"), AdHocResult::AdHoc(1, "lowprec"));
+    ///
+    /// let x: AdHocResult<u32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(x.downgrade("lowprec"), AdHocResult::Err("Error"));
+    /// ```
+    pub fn downgrade(self, error: E) -> AdHocResult<T, E> {
+        match self {
+            AdHocResult::Ok(v) => AdHocResult::AdHoc(v, error),
+            AdHocResult::AdHoc(v, _) => AdHocResult::AdHoc(v, error),
+            AdHocResult::Err(e) => AdHocResult::Err(e),
+        }
+    }
+
+    /// Promotes an `AdHoc` to an `Ok`, dropping its warning.
+    ///
+    /// `Ok` and `Err` are left unchanged. This is the inverse of
+    /// [`downgrade`](AdHocResult::downgrade) for clean values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let x: AdHocResult<u32, &str> = AdHocResult::Ok(1);
+    /// assert_eq!(x.downgrade("lowprec").promote(), AdHocResult::Ok(1));
+    ///
+    /// let x: AdHocResult<u32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(x.promote(), AdHocResult::Err("Error"));
+    /// ```
+    pub fn promote(self) -> AdHocResult<T, E> {
+        match self {
+            AdHocResult::AdHoc(v, _) => AdHocResult::Ok(v),
+            other => other,
+        }
+    }
+
+    /// Converts the result into a `Result` whose error is an RFC 7807 problem.
+    ///
+    /// An `Ok` returns its value. For an `AdHoc` or `Err`, the recommended value is
+    /// discarded and a [`ProblemDetails`] is built with:
+    ///
+    /// * `status` - the given `status`
+    /// * `title` - the canonical reason phrase of `status`, such as `"Unprocessable Entity"`
+    ///   for 422, or `"Unknown Status"` if the code has none
+    /// * `detail` - the `Display` of the error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let x: AdHocResult<u32, &str> = AdHocResult::AdHoc(2, "Residual too large");
+    /// let problem = x.into_problem_details(422).unwrap_err();
+    /// assert_eq!(
+    ///     serde_json::to_value(&problem).unwrap(),
+    ///     serde_json::json!({
+    ///         "status": 422,
+    ///         "title": "Unprocessable Entity",
+    ///         "detail": "Residual too large",
+    ///     }),
+    /// );
+    ///
+    /// let x: AdHocResult<u32, &str> = AdHocResult::Ok(2);
+    /// assert_eq!(x.into_problem_details(422), Ok(2));
+    /// ```
+    #[cfg(all(feature = "serde", feature = "http"))]
+    pub fn into_problem_details(self, status: u16) -> Result<T, ProblemDetails>
+    where
+        E: std::fmt::Display,
+    {
+        let problem = |e: E| ProblemDetails {
+            status,
+            title: http::StatusCode::from_u16(status)
+                .ok()
+                .and_then(|code| code.canonical_reason())
+                .unwrap_or("Unknown Status")
+                .to_string(),
+            detail: e.to_string(),
+        };
+        match self {
+            AdHocResult::Ok(v) => Ok(v),
+            AdHocResult::AdHoc(_, e) => Err(problem(e)),
+            AdHocResult::Err(e) => Err(problem(e)),
+        }
+    }
+
+    /// Boxes the value of an `Ok` or `AdHoc`, keeping the variant and error.
+    ///
+    /// `Err` is passed through unchanged. See
+    /// [`unbox_value`](AdHocResult::unbox_value) for the inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let x: AdHocResult<u32, &str> = AdHocResult::AdHoc(2, "Not ideal");
+    /// assert_eq!(x.boxed_value(), AdHocResult::AdHoc(Box::new(2), "Not ideal"));
+    /// ```
+    pub fn boxed_value(self) -> AdHocResult<Box<T>, E> {
+        self.map(Box::new)
+    }
+
+    /// Converts the `AdHocResult<T, E>` into a `Result<T, AdHocError<E>>` for error-reporting stacks.
+    ///
+    /// Like `to_result_tagged`, this discards the recommended value of an `AdHoc`,
+    /// but the error is wrapped in an [`AdHocError`], which implements
+    /// `std::error::Error` and can be propagated with `?` into a
+    /// `Box<dyn std::error::Error>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    /// use std::error::Error;
+    ///
+    /// fn solve() -> Result<f64, Box<dyn Error>> {
+    ///     let x: AdHocResult<f64, &str> = AdHocResult::AdHoc(2.0, "Ill-conditioned");
+    ///     Ok(x.into_result_reporting()?)
+    /// }
+    ///
+    /// let err = solve().unwrap_err();
+    /// assert_eq!(err.to_string(), "degraded result discarded: Ill-conditioned");
+    /// assert!(err.source().is_none());
+    ///
+    /// let x: AdHocResult<f64, &str> = AdHocResult::Err("Singular");
+    /// assert_eq!(x.into_result_reporting().unwrap_err().to_string(), "hard error: Singular");
+    /// ```
+    pub fn into_result_reporting(self) -> Result<T, AdHocError<E>> {
+        self.to_result_tagged()
+            .map_err(|(origin, error)| AdHocError { origin, error })
+    }
+
+    /// Migrates the error type of a result, keeping any recommendation intact.
+    ///
+    /// This is an alias of `map_err` named for refactors that change `E`: `convert`
+    /// is applied to the error of both `AdHoc` and `Err`, and the recommended value
+    /// of an `AdHoc` survives the migration unchanged. `Ok` is passed through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum SolveError {
+    ///     IllConditioned(String),
+    /// }
+    ///
+    /// let old: AdHocResult<f64, String> = AdHocResult::AdHoc(2.0, "cond = 1e12".to_string());
+    /// let new = old.migrate_err(SolveError::IllConditioned);
+    /// assert_eq!(new, AdHocResult::AdHoc(2.0, SolveError::IllConditioned("cond = 1e12".to_string())));
+    ///
+    /// let old: AdHocResult<f64, String> = AdHocResult::Ok(2.0);
+    /// assert_eq!(old.migrate_err(SolveError::IllConditioned), AdHocResult::Ok(2.0));
+    /// ```
+    pub fn migrate_err<E2>(self, convert: impl FnOnce(E) -> E2) -> AdHocResult<T, E2> {
+        self.map_err(convert)
+    }
+
+    /// Increments a `metrics` counter labeled with the variant, returning the result unchanged.
+    ///
+    /// The counter `counter_name` is incremented by one with a single `variant` label
+    /// set to `"ok"`, `"adhoc"` or `"err"`, so each call site gets a per-variant tally
+    /// in whatever recorder is installed through the
+    /// [`metrics`](https://crates.io/crates/metrics) facade.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    /// use metrics::{Counter, CounterFn, Gauge, Histogram, Key, KeyName, Metadata, Recorder, SharedString, Unit};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// struct Tally {
+    ///     key: Key,
+    ///     seen: Arc<Mutex<Vec<(String, String, u64)>>>,
+    /// }
+    ///
+    /// impl CounterFn for Tally {
+    ///     fn increment(&self, value: u64) {
+    ///         let variant = self.key.labels().find(|l| l.key() == "variant").unwrap().value().to_string();
+    ///         self.seen.lock().unwrap().push((self.key.name().to_string(), variant, value));
+    ///     }
+    ///     fn absolute(&self, _: u64) {}
+    /// }
+    ///
+    /// struct Capture(Arc<Mutex<Vec<(String, String, u64)>>>);
+    ///
+    /// impl Recorder for Capture {
+    ///     fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+    ///     fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+    ///     fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+    ///     fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+    ///         Counter::from_arc(Arc::new(Tally { key: key.clone(), seen: self.0.clone() }))
+    ///     }
+    ///     fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge { Gauge::noop() }
+    ///     fn register_histogram(&self, _: &Key, _: &Metadata<'_>) -> Histogram { Histogram::noop() }
+    /// }
+    ///
+    /// let seen = Arc::new(Mutex::new(Vec::new()));
+    /// let recorder = Capture(seen.clone());
+    /// metrics::with_local_recorder(&recorder, || {
+    ///     let ok: AdHocResult<u32, &str> = AdHocResult::Ok(1);
+    ///     assert_eq!(ok.count_metric("solves"), AdHocResult::Ok(1));
+    ///     let adhoc: AdHocResult<u32, &str> = AdHocResult::AdHoc(2, "Not ideal");
+    ///     assert_eq!(adhoc.count_metric("solves"), AdHocResult::AdHoc(2, "Not ideal"));
+    ///     let err: AdHocResult<u32, &str> = AdHocResult::Err("Error");
+    ///     assert_eq!(err.count_metric("solves"), AdHocResult::Err("Error"));
+    /// });
+    ///
+    /// let seen = seen.lock().unwrap();
+    /// assert_eq!(*seen, [
+    ///     ("solves".to_string(), "ok".to_string(), 1),
+    ///     ("solves".to_string(), "adhoc".to_string(), 1),
+    ///     ("solves".to_string(), "err".to_string(), 1),
+    /// ]);
+    /// ```
+    #[cfg(feature = "metrics")]
+    pub fn count_metric(self, counter_name: &'static str) -> Self {
+        let variant = match self {
+            AdHocResult::Ok(_) => "ok",
+            AdHocResult::AdHoc(_, _) => "adhoc",
+            AdHocResult::Err(_) => "err",
+        };
+        metrics::counter!(counter_name, "variant" => variant).increment(1);
+        self
+    }
+}
+
+impl<T, E> From<Result<T, E>> for AdHocResult<T, E> {
+    /// Converts a `Result<T, E>` into an `AdHocResult<T, E>`.
+    ///
+    /// This conversion maps `Ok` variants to `AdHocResult::Ok` and
+    /// `Err` variants to `AdHocResult::Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok_result: Result<i32, &str> = Ok(42);
+    /// let adhoc_ok: AdHocResult<i32, &str> = ok_result.into();
+    /// assert_eq!(adhoc_ok, AdHocResult::Ok(42));
+    ///
+    /// let err_result: Result<i32, &str> = Err("Error");
+    /// let adhoc_err: AdHocResult<i32, &str> = err_result.into();
+    /// assert_eq!(adhoc_err, AdHocResult::Err("Error"));
+    /// ```
+    fn from(result: Result<T, E>) -> Self {
+        match result {
+            Ok(v) => AdHocResult::Ok(v),
+            Err(e) => AdHocResult::Err(e),
+        }
+    }
+}
+
+impl<T, E> From<AdHocResult<T, E>> for Result<T, E> {
+    /// Converts an `AdHocResult<T, E>` into a `Result<T, E>`.
+    ///
+    /// This conversion maps `AdHocResult::Ok` to `Ok` and both
+    /// `AdHocResult::AdHoc` and `AdHocResult::Err` to `Err`.
+    /// For `AdHoc`, the recommended value is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let adhoc_ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// let ok_result: Result<i32, &str> = adhoc_ok.into();
+    /// assert_eq!(ok_result, Ok(42));
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// let adhoc_result: Result<i32, &str> = adhoc.into();
+    /// assert_eq!(adhoc_result, Err("Not perfect"));
+    ///
+    /// let adhoc_err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// let err_result: Result<i32, &str> = adhoc_err.into();
+    /// assert_eq!(err_result, Err("Error"));
+    /// ```
+    fn from(adhoc: AdHocResult<T, E>) -> Self {
+        adhoc.to_result()
+    }
+}
+
+impl<T, E> From<AdHocResult<T, E>> for std::ops::ControlFlow<E, T> {
+    /// Converts an `AdHocResult<T, E>` into a `ControlFlow<E, T>`.
+    ///
+    /// This conversion maps both `AdHocResult::Ok` and `AdHocResult::AdHoc`
+    /// to `Continue` with the value, and `AdHocResult::Err` to `Break`.
+    /// For `AdHoc`, the error is discarded and the recommended value is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    /// use std::ops::ControlFlow;
+    ///
+    /// let ok: AdHocResult<i32, &str> = AdHocResult::Ok(42);
+    /// assert_eq!(ControlFlow::from(ok), ControlFlow::Continue(42));
+    ///
+    /// let adhoc: AdHocResult<i32, &str> = AdHocResult::AdHoc(42, "Not perfect");
+    /// let flow: ControlFlow<&str, i32> = adhoc.into();
+    /// assert_eq!(flow, ControlFlow::Continue(42));
+    ///
+    /// let err: AdHocResult<i32, &str> = AdHocResult::Err("Error");
+    /// let flow: ControlFlow<&str, i32> = err.into();
+    /// assert_eq!(flow, ControlFlow::Break("Error"));
+    /// ```
+    fn from(adhoc: AdHocResult<T, E>) -> Self {
+        match adhoc {
+            AdHocResult::Ok(v) => std::ops::ControlFlow::Continue(v),
+            AdHocResult::AdHoc(v, _) => std::ops::ControlFlow::Continue(v),
+            AdHocResult::Err(e) => std::ops::ControlFlow::Break(e),
+        }
+    }
+}
+
+impl<T, E> FromIterator<AdHocResult<T, E>> for AdHocResult<Vec<T>, E> {
+    /// Collects an iterator of `AdHocResult`s into a single `AdHocResult<Vec<T>, E>`.
+    ///
+    /// The first `Err` is returned immediately, and no further elements are consumed.
+    /// Otherwise the values of all `Ok` and `AdHoc` elements are collected in order:
+    /// if any element was an `AdHoc`, the result is `AdHoc(values, e)` with the error
+    /// of the first `AdHoc`, and if every element was an `Ok`, the result is `Ok(values)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let all_ok: AdHocResult<Vec<i32>, &str> = vec![AdHocResult::Ok(1), AdHocResult::Ok(2)].into_iter().collect();
+    /// assert_eq!(all_ok, AdHocResult::Ok(vec![1, 2]));
+    ///
+    /// let degraded: AdHocResult<Vec<i32>, &str> = vec![
+    ///     AdHocResult::Ok(1),
+    ///     AdHocResult::AdHoc(2, "First warning"),
+    ///     AdHocResult::AdHoc(3, "Second warning"),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// assert_eq!(degraded, AdHocResult::AdHoc(vec![1, 2, 3], "First warning"));
+    ///
+    /// let mut consumed = 0;
+    /// let failed: AdHocResult<Vec<i32>, &str> = vec![
+    ///     AdHocResult::AdHoc(1, "Not ideal"),
+    ///     AdHocResult::Err("Error"),
+    ///     AdHocResult::Ok(3),
+    /// ]
+    /// .into_iter()
+    /// .inspect(|_| consumed += 1)
+    /// .collect();
+    /// assert_eq!(failed, AdHocResult::Err("Error"));
+    /// // Collection stopped at the first `Err`
+    /// assert_eq!(consumed, 2);
+    /// ```
+    fn from_iter<I: IntoIterator<Item = AdHocResult<T, E>>>(iter: I) -> Self {
+        let mut values = Vec::new();
+        let mut warning = None;
+        for result in iter {
+            match result {
+                AdHocResult::Ok(v) => values.push(v),
+                AdHocResult::AdHoc(v, e) => {
+                    values.push(v);
+                    warning.get_or_insert(e);
+                }
+                AdHocResult::Err(e) => return AdHocResult::Err(e),
+            }
+        }
+        match warning {
+            Some(e) => AdHocResult::AdHoc(values, e),
+            None => AdHocResult::Ok(values),
+        }
+    }
+}
+
+/// Yields the usable value of an `AdHocResult`, if any.
+///
+/// Exactly one value is yielded for an `Ok` or an `AdHoc`, since the recommended
+/// value counts as usable, and nothing is yielded for an `Err`. The `&` and `&mut`
+/// variants of `IntoIterator` yield references to the value instead.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::AdHocResult;
+///
+/// let results: Vec<AdHocResult<u32, &str>> = vec![
+///     AdHocResult::Ok(1),
+///     AdHocResult::AdHoc(2, "Not ideal"),
+///     AdHocResult::Err("Error"),
+/// ];
+/// let usable: Vec<u32> = results.into_iter().flatten().collect();
+/// assert_eq!(usable, [1, 2]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntoIter<T> {
+    inner: Option<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.inner.take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = usize::from(self.inner.is_some());
+        (n, Some(n))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.inner.take()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> std::iter::FusedIterator for IntoIter<T> {}
+
+impl<T, E> IntoIterator for AdHocResult<T, E> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Returns an iterator over the value of an `Ok` or `AdHoc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let x: AdHocResult<u32, &str> = AdHocResult::AdHoc(2, "Not ideal");
+    /// let mut seen = Vec::new();
+    /// for v in x {
+    ///     seen.push(v);
+    /// }
+    /// assert_eq!(seen, [2]);
+    ///
+    /// let x: AdHocResult<u32, &str> = AdHocResult::Ok(1);
+    /// assert_eq!(x.into_iter().collect::<Vec<_>>(), [1]);
+    ///
+    /// let x: AdHocResult<u32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(x.into_iter().collect::<Vec<_>>(), Vec::<u32>::new());
+    /// ```
+    fn into_iter(self) -> IntoIter<T> {
+        let inner = match self {
+            AdHocResult::Ok(v) => Some(v),
+            AdHocResult::AdHoc(v, _) => Some(v),
+            AdHocResult::Err(_) => None,
+        };
+        IntoIter { inner }
+    }
+}
+
+impl<'a, T, E> IntoIterator for &'a AdHocResult<T, E> {
+    type Item = &'a T;
+    type IntoIter = IntoIter<&'a T>;
+
+    /// Returns an iterator over a reference to the value of an `Ok` or `AdHoc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let x: AdHocResult<u32, &str> = AdHocResult::AdHoc(2, "Not ideal");
+    /// assert_eq!((&x).into_iter().collect::<Vec<_>>(), [&2]);
+    ///
+    /// let x: AdHocResult<u32, &str> = AdHocResult::Err("Error");
+    /// assert_eq!((&x).into_iter().count(), 0);
+    /// ```
+    fn into_iter(self) -> IntoIter<&'a T> {
+        self.as_ref().into_iter()
+    }
+}
+
+impl<'a, T, E> IntoIterator for &'a mut AdHocResult<T, E> {
+    type Item = &'a mut T;
+    type IntoIter = IntoIter<&'a mut T>;
+
+    /// Returns an iterator over a mutable reference to the value of an `Ok` or `AdHoc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let mut x: AdHocResult<u32, &str> = AdHocResult::AdHoc(2, "Not ideal");
+    /// for v in &mut x {
+    ///     *v += 1;
+    /// }
+    /// assert_eq!(x, AdHocResult::AdHoc(3, "Not ideal"));
+    /// ```
+    fn into_iter(self) -> IntoIter<&'a mut T> {
+        self.as_mut().into_iter()
+    }
+}
+
+
+// Additional helper trait implementation
+impl<T, E> AdHocResult<T, E> {
+    /// Creates a new `AdHocResult` in the `Ok` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let x: AdHocResult<u32, &str> = AdHocResult::new_ok(42);
+    /// assert_eq!(x.unwrap(), 42);
+    /// ```
+    pub fn new_ok(value: T) -> Self {
+        AdHocResult::Ok(value)
+    }
+
+    /// Creates a new `AdHocResult` in the `AdHoc` variant with a recommended value and an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let x: AdHocResult<u32, &str> = AdHocResult::new_adhoc(42, "Not ideal");
+    /// assert_eq!(x.unwrap_adhoc(), 42);
+    /// ```
+    pub fn new_adhoc(value: T, error: E) -> Self {
+        AdHocResult::AdHoc(value, error)
+    }
+
+    /// Creates a new `AdHocResult` in the `Err` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let x: AdHocResult<u32, &str> = AdHocResult::new_err("Error occurred");
+    /// assert!(matches!(x, AdHocResult::Err(_)));
+    /// ```
+    pub fn new_err(error: E) -> Self {
+        AdHocResult::Err(error)
+    }
+
+    /// Constructs an `Ok` in place in uninitialized memory, returning a reference to it.
+    ///
+    /// This is a safe wrapper around `MaybeUninit::write`. Any value previously stored
+    /// in `slot` is overwritten without being dropped, and the caller is responsible for
+    /// eventually dropping the emplaced result, e.g. with `MaybeUninit::assume_init_drop`,
+    /// since `MaybeUninit` never drops its contents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut slot = MaybeUninit::<AdHocResult<u32, &str>>::uninit();
+    /// let x = AdHocResult::emplace_ok(&mut slot, 42);
+    /// assert!(matches!(x, AdHocResult::Ok(42)));
+    /// ```
+    #[inline]
+    pub fn emplace_ok(slot: &mut std::mem::MaybeUninit<Self>, value: T) -> &mut Self {
+        slot.write(AdHocResult::Ok(value))
+    }
+
+    /// Constructs an `AdHoc` in place in uninitialized memory, returning a reference to it.
+    ///
+    /// The same contract as `emplace_ok` applies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut slot = MaybeUninit::<AdHocResult<u32, &str>>::uninit();
+    /// let x = AdHocResult::emplace_adhoc(&mut slot, 42, "Not ideal");
+    /// assert!(matches!(x, AdHocResult::AdHoc(42, "Not ideal")));
+    /// ```
+    #[inline]
+    pub fn emplace_adhoc(slot: &mut std::mem::MaybeUninit<Self>, value: T, error: E) -> &mut Self {
+        slot.write(AdHocResult::AdHoc(value, error))
+    }
+
+    /// Constructs an `Err` in place in uninitialized memory, returning a reference to it.
+    ///
+    /// The same contract as `emplace_ok` applies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut slot = MaybeUninit::<AdHocResult<u32, String>>::uninit();
+    /// let x = AdHocResult::emplace_err(&mut slot, "Error occurred".to_string());
+    /// assert!(matches!(x, AdHocResult::Err(e) if e == "Error occurred"));
+    /// // Drop the emplaced result, since `MaybeUninit` does not
+    /// unsafe { slot.assume_init_drop() };
+    /// ```
+    #[inline]
+    pub fn emplace_err(slot: &mut std::mem::MaybeUninit<Self>, error: E) -> &mut Self {
+        slot.write(AdHocResult::Err(error))
+    }
+}
+
+impl<T, E> AdHocResult<Vec<T>, E> {
+    /// Transforms each element of the value vector together with its index.
+    ///
+    /// For `Ok` and `AdHoc`, `f(i, element)` is applied to every element in order,
+    /// and the warning of an `AdHoc` is kept. `Err` is passed through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let adhoc: AdHocResult<Vec<i32>, &str> = AdHocResult::AdHoc(vec![10, 20, 30], "Not ideal");
+    /// let scaled = adhoc.map_each_indexed(|i, x| x * (i as i32 + 1));
+    /// assert!(matches!(scaled, AdHocResult::AdHoc(v, "Not ideal") if v == [10, 40, 90]));
+    ///
+    /// let err: AdHocResult<Vec<i32>, &str> = AdHocResult::Err("Error");
+    /// assert!(matches!(err.map_each_indexed(|i, x| x + i as i32), AdHocResult::Err("Error")));
+    /// ```
+    pub fn map_each_indexed<U, F: FnMut(usize, T) -> U>(self, mut f: F) -> AdHocResult<Vec<U>, E> {
+        match self {
+            AdHocResult::Ok(v) => AdHocResult::Ok(v.into_iter().enumerate().map(|(i, x)| f(i, x)).collect()),
+            AdHocResult::AdHoc(v, e) => {
+                AdHocResult::AdHoc(v.into_iter().enumerate().map(|(i, x)| f(i, x)).collect(), e)
+            }
+            AdHocResult::Err(e) => AdHocResult::Err(e),
+        }
+    }
+}
+
+impl<T, E> AdHocResult<Vec<AdHocResult<T, E>>, E> {
+    /// Counts the `AdHoc` results at both levels of a nested result.
+    ///
+    /// The outer result counts as one if it is an `AdHoc`, and every inner element
+    /// that is an `AdHoc` counts as one more. Inner elements are only visible through
+    /// an outer `Ok` or `AdHoc`, so an outer `Err` counts as zero. Deeper nesting
+    /// inside the inner values is not inspected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let nested: AdHocResult<Vec<AdHocResult<f64, &str>>, &str> = AdHocResult::AdHoc(
+    ///     vec![
+    ///         AdHocResult::Ok(1.0),
+    ///         AdHocResult::AdHoc(2.0, "Ill-conditioned"),
+    ///         AdHocResult::Err("Singular"),
+    ///         AdHocResult::AdHoc(4.0, "Ill-conditioned"),
+    ///     ],
+    ///     "Partially degraded",
+    /// );
+    /// assert_eq!(nested.count_degraded(), 3);
+    ///
+    /// let clean_outer: AdHocResult<Vec<AdHocResult<f64, &str>>, &str> =
+    ///     AdHocResult::Ok(vec![AdHocResult::AdHoc(2.0, "Ill-conditioned")]);
+    /// assert_eq!(clean_outer.count_degraded(), 1);
+    ///
+    /// let failed: AdHocResult<Vec<AdHocResult<f64, &str>>, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(failed.count_degraded(), 0);
+    /// ```
+    pub fn count_degraded(&self) -> usize {
+        let count_inner = |inner: &[AdHocResult<T, E>]| {
+            inner.iter().filter(|r| matches!(r, AdHocResult::AdHoc(..))).count()
+        };
+        match self {
+            AdHocResult::Ok(inner) => count_inner(inner),
+            AdHocResult::AdHoc(inner, _) => 1 + count_inner(inner),
+            AdHocResult::Err(_) => 0,
+        }
+    }
+}
+
+impl<T: ?Sized + ToOwned, E: ?Sized + ToOwned> AdHocResult<&T, &E> {
+    /// Converts a result holding borrowed data into one holding owned data.
+    ///
+    /// Each borrowed payload is converted with `ToOwned::to_owned`, which usually
+    /// clones or allocates, so the cost is that of copying the value and error.
+    /// This detaches the result from the borrow, e.g. when returning it from a
+    /// function that borrowed its inputs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let source = String::from("approximate solution");
+    /// let borrowed: AdHocResult<&str, &str> = AdHocResult::AdHoc(&source[..11], "Not perfect");
+    /// let owned: AdHocResult<String, String> = borrowed.into_owned();
+    /// drop(source);
+    /// assert!(matches!(owned, AdHocResult::AdHoc(v, e) if v == "approximate" && e == "Not perfect"));
+    /// ```
+    pub fn into_owned(self) -> AdHocResult<T::Owned, E::Owned> {
+        match self {
+            AdHocResult::Ok(v) => AdHocResult::Ok(v.to_owned()),
+            AdHocResult::AdHoc(v, e) => AdHocResult::AdHoc(v.to_owned(), e.to_owned()),
+            AdHocResult::Err(e) => AdHocResult::Err(e.to_owned()),
+        }
+    }
+}
+
+impl<T, E> AdHocResult<Box<T>, E> {
+    /// Moves the value of an `Ok` or `AdHoc` out of its box, keeping the variant and error.
+    ///
+    /// `Err` is passed through unchanged. This is the inverse of
+    /// [`boxed_value`](AdHocResult::boxed_value).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let x: AdHocResult<String, &str> = AdHocResult::AdHoc("approximate".to_string(), "Not ideal");
+    /// let boxed = x.boxed_value();
+    /// assert_eq!(boxed.unbox_value(), AdHocResult::AdHoc("approximate".to_string(), "Not ideal"));
+    ///
+    /// let x: AdHocResult<Box<u32>, &str> = AdHocResult::Err("Error");
+    /// assert_eq!(x.unbox_value(), AdHocResult::Err("Error"));
+    /// ```
+    pub fn unbox_value(self) -> AdHocResult<T, E> {
+        self.map(|v| *v)
+    }
+}
+
+impl<E> AdHocResult<Box<dyn std::any::Any>, E> {
+    /// Attempts to downcast a type-erased value to `U`.
+    ///
+    /// For `Ok` and `AdHoc`, the boxed value is downcast and unboxed, keeping the
+    /// variant and the warning of an `AdHoc`. If the value is not a `U`, the result
+    /// becomes `Err(on_mismatch(value))` with the original box, and the warning of
+    /// an `AdHoc` is dropped. `Err` is passed through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    /// use std::any::Any;
+    ///
+    /// let erased: AdHocResult<Box<dyn Any>, String> = AdHocResult::AdHoc(Box::new(42_i32), "Not perfect".to_string());
+    /// let typed = erased.downcast_value::<i32, _>(|_| "Unexpected type".to_string());
+    /// assert!(matches!(typed, AdHocResult::AdHoc(42, e) if e == "Not perfect"));
+    ///
+    /// let erased: AdHocResult<Box<dyn Any>, String> = AdHocResult::Ok(Box::new("text"));
+    /// let typed = erased.downcast_value::<i32, _>(|_| "Unexpected type".to_string());
+    /// assert!(matches!(typed, AdHocResult::Err(e) if e == "Unexpected type"));
+    /// ```
+    pub fn downcast_value<U: 'static, F: FnOnce(Box<dyn std::any::Any>) -> E>(
+        self,
+        on_mismatch: F,
+    ) -> AdHocResult<U, E> {
+        match self {
+            AdHocResult::Ok(v) => match v.downcast::<U>() {
+                Ok(u) => AdHocResult::Ok(*u),
+                Err(v) => AdHocResult::Err(on_mismatch(v)),
+            },
+            AdHocResult::AdHoc(v, e) => match v.downcast::<U>() {
+                Ok(u) => AdHocResult::AdHoc(*u, e),
+                Err(v) => AdHocResult::Err(on_mismatch(v)),
+            },
+            AdHocResult::Err(e) => AdHocResult::Err(e),
+        }
+    }
+}
+
+impl<F, E> AdHocResult<F, E> {
+    /// Calls a deferred value, turning a result holding a thunk into one holding its output.
+    ///
+    /// For `Ok` and `AdHoc`, the thunk is called exactly once and its output replaces it,
+    /// keeping the variant and the warning of an `AdHoc`. `Err` is passed through and no
+    /// thunk is called, so expensive recommended values are only built when needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let ok: AdHocResult<Box<dyn FnOnce() -> i32>, &str> = AdHocResult::Ok(Box::new(|| 6 * 7));
+    /// assert!(matches!(ok.force(), AdHocResult::Ok(42)));
+    ///
+    /// let adhoc = AdHocResult::<_, &str>::AdHoc(|| vec![1.0, 2.0], "Not converged");
+    /// assert!(matches!(adhoc.force(), AdHocResult::AdHoc(v, "Not converged") if v == [1.0, 2.0]));
+    ///
+    /// let err: AdHocResult<fn() -> i32, &str> = AdHocResult::Err("Error");
+    /// assert!(matches!(err.force(), AdHocResult::Err("Error")));
+    /// ```
+    pub fn force<T>(self) -> AdHocResult<T, E>
+    where
+        F: FnOnce() -> T,
+    {
+        match self {
+            AdHocResult::Ok(f) => AdHocResult::Ok(f()),
+            AdHocResult::AdHoc(f, e) => AdHocResult::AdHoc(f(), e),
+            AdHocResult::Err(e) => AdHocResult::Err(e),
+        }
+    }
+}
+
+impl<T, E: Ord> AdHocResult<T, Vec<E>> {
+    /// Sorts and deduplicates the error vector of an `AdHoc` or `Err`.
+    ///
+    /// This makes accumulated warnings independent of processing order, e.g. for
+    /// golden-file comparisons. `Ok` is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::AdHocResult;
+    ///
+    /// let adhoc: AdHocResult<i32, Vec<&str>> =
+    ///     AdHocResult::AdHoc(42, vec!["row 7", "row 3", "row 7", "row 1"]);
+    /// assert!(matches!(
+    ///     adhoc.normalize_warnings(),
+    ///     AdHocResult::AdHoc(42, w) if w == ["row 1", "row 3", "row 7"]
+    /// ));
+    /// ```
+    pub fn normalize_warnings(self) -> Self {
+        let normalize = |mut errors: Vec<E>| {
+            errors.sort();
+            errors.dedup();
+            errors
+        };
+        match self {
+            AdHocResult::Ok(v) => AdHocResult::Ok(v),
+            AdHocResult::AdHoc(v, e) => AdHocResult::AdHoc(v, normalize(e)),
+            AdHocResult::Err(e) => AdHocResult::Err(normalize(e)),
+        }
+    }
+}
+
+impl<T> AdHocResult<T, (Severity, String)> {
+    /// Escalates an `AdHoc` to a hard `Err` if its severity is at least `at_least`.
+    ///
+    /// An escalated `AdHoc(v, (severity, msg))` becomes `Err((severity, msg))`, and its
+    /// recommended value is discarded. An `AdHoc` of lower severity, `Ok`, and `Err`
+    /// are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::{AdHocResult, Severity};
+    ///
+    /// let critical = AdHocResult::AdHoc(1.0, (Severity::Critical, "Residual exploded".to_string()));
+    /// assert!(matches!(critical.escalate_if(Severity::Warning), AdHocResult::Err((Severity::Critical, _))));
+    ///
+    /// let info = AdHocResult::AdHoc(1.0, (Severity::Info, "Used fallback pivot".to_string()));
+    /// assert!(matches!(info.escalate_if(Severity::Warning), AdHocResult::AdHoc(1.0, (Severity::Info, _))));
+    /// ```
+    pub fn escalate_if(self, at_least: Severity) -> Self {
+        match self {
+            AdHocResult::AdHoc(_, (severity, msg)) if severity >= at_least => AdHocResult::Err((severity, msg)),
+            other => other,
+        }
+    }
+}
+
+/// A result in a tree of computations, together with the results of its children.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::{AdHocResult, NodeResult, ResultKind};
+///
+/// let leaf = |value| NodeResult { value, children: Vec::new() };
+///
+/// let tree: NodeResult<f64, &str> = NodeResult {
+///     value: AdHocResult::Ok(1.0),
+///     children: vec![
+///         leaf(AdHocResult::Ok(2.0)),
+///         NodeResult {
+///             value: AdHocResult::Ok(3.0),
+///             children: vec![leaf(AdHocResult::AdHoc(4.0, "Ill-conditioned"))],
+///         },
+///     ],
+/// };
+///
+/// assert_eq!(tree.value.kind(), ResultKind::Ok);
+/// assert_eq!(tree.rollup_status(), ResultKind::AdHoc);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeResult<T, E> {
+    /// The result computed at this node
+    pub value: AdHocResult<T, E>,
+    /// The nodes computed beneath this one
+    pub children: Vec<NodeResult<T, E>>,
+}
+
+impl<T, E> NodeResult<T, E> {
+    /// Returns the worst status of this node and all of its descendants.
+    ///
+    /// The kind of this node's own result is combined with the rolled-up status of
+    /// every child, recursively down to the leaves, and the worst kind by
+    /// `Ok < AdHoc < Err` is returned. A degraded or failed result anywhere below
+    /// a node is therefore visible at the node itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::{AdHocResult, NodeResult, ResultKind};
+    ///
+    /// let leaf: NodeResult<f64, &str> = NodeResult { value: AdHocResult::Err("Singular"), children: Vec::new() };
+    /// assert_eq!(leaf.rollup_status(), ResultKind::Err);
+    /// ```
+    pub fn rollup_status(&self) -> ResultKind {
+        self.children
+            .iter()
+            .map(NodeResult::rollup_status)
+            .fold(self.value.kind(), std::cmp::max)
+    }
+}
+
+/// A result bundled with metadata about how it was produced.
+///
+/// The metadata, such as an iteration count or a final residual, travels alongside
+/// the result without being folded into the error type.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::{AdHocResult, WithMeta};
+///
+/// struct SolveInfo {
+///     iterations: usize,
+///     residual: f64,
+/// }
+///
+/// let solved = AdHocResult::<f64, &str>::AdHoc(2.0, "Not converged")
+///     .with_meta(SolveInfo { iterations: 100, residual: 1e-3 });
+/// assert_eq!(solved.meta.iterations, 100);
+/// assert_eq!(solved.result, AdHocResult::AdHoc(2.0, "Not converged"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithMeta<T, E, M> {
+    /// The wrapped result
+    pub result: AdHocResult<T, E>,
+    /// Metadata about how the result was produced
+    pub meta: M,
+}
+
+impl<T, E, M> WithMeta<T, E, M> {
+    /// Bundles a result with its metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::{AdHocResult, WithMeta};
+    ///
+    /// let x: WithMeta<u32, &str, usize> = WithMeta::new(AdHocResult::Ok(42), 3);
+    /// assert_eq!(x.meta, 3);
+    /// ```
+    pub fn new(result: AdHocResult<T, E>, meta: M) -> Self {
+        WithMeta { result, meta }
+    }
+
+    /// Transforms the wrapped result with `f`, keeping the metadata intact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ad_hoc_result::{AdHocResult, WithMeta};
+    ///
+    /// let x: WithMeta<f64, &str, (usize, f64)> = WithMeta::new(AdHocResult::AdHoc(4.0, "Not converged"), (100, 1e-3));
+    /// let y = x.map_result(|r| r.map(|v| v.sqrt()));
+    /// assert_eq!(y.result, AdHocResult::AdHoc(2.0, "Not converged"));
+    /// assert_eq!(y.meta, (100, 1e-3));
+    /// ```
+    pub fn map_result<U, F: FnOnce(AdHocResult<T, E>) -> AdHocResult<U, E>>(self, f: F) -> WithMeta<U, E, M> {
+        WithMeta {
+            result: f(self.result),
+            meta: self.meta,
+        }
+    }
+}
+
+/// An error produced by `into_result_reporting`, recording where it came from.
+///
+/// The `Display` output is prefixed with `"hard error"` for an `Err` and with
+/// `"degraded result discarded"` for an `AdHoc` whose recommended value was thrown
+/// away. It implements `std::error::Error` when `E: Display + Debug + 'static`; since
+/// `E` itself is not required to be an error, `source` always returns `None`.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::{AdHocError, Origin};
+///
+/// let err = AdHocError { origin: Origin::Fatal, error: "Singular" };
+/// assert_eq!(err.to_string(), "hard error: Singular");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdHocError<E> {
+    /// Whether the error came from an `Err` or a discarded `AdHoc`
+    pub origin: Origin,
+    /// The wrapped error
+    pub error: E,
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for AdHocError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.origin {
+            Origin::Recommendation => write!(f, "degraded result discarded: {}", self.error),
+            Origin::Fatal => write!(f, "hard error: {}", self.error),
+        }
+    }
+}
+
+impl<E: std::fmt::Display + std::fmt::Debug + 'static> std::error::Error for AdHocError<E> {}
+
+/// An RFC 7807 problem details body describing a failed result.
+///
+/// Produced by [`AdHocResult::into_problem_details`]. Only the `status`, `title`
+/// and `detail` members of the RFC are populated.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::ProblemDetails;
+///
+/// let problem = ProblemDetails { status: 404, title: "Not Found".to_string(), detail: "No such item".to_string() };
+/// assert_eq!(
+///     serde_json::to_value(&problem).unwrap(),
+///     serde_json::json!({ "status": 404, "title": "Not Found", "detail": "No such item" }),
+/// );
+/// ```
+#[cfg(all(feature = "serde", feature = "http"))]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ProblemDetails {
+    /// The HTTP status code
+    pub status: u16,
+    /// The canonical reason phrase of the status code
+    pub title: String,
+    /// The `Display` of the error
+    pub detail: String,
+}
+
+/// Returns the best result of an iterator by the `Ok > AdHoc > Err` priority.
+///
+/// Elements are consumed lazily, and the iterator is not advanced any further
+/// once an `Ok` is found, since nothing can beat a clean success. Among results
+/// of equal priority, the first one seen wins. If the iterator is empty, there
+/// is no candidate to return, and the result is `Err(empty)`.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::{fold_best, AdHocResult};
+///
+/// let mut produced = 0;
+/// let results = (0..5).map(|i| {
+///     produced += 1;
+///     match i {
+///         0 => AdHocResult::Err("Diverged"),
+///         1 => AdHocResult::AdHoc(i, "Not ideal"),
+///         _ => AdHocResult::Ok(i),
+///     }
+/// });
+///
+/// let best = fold_best(results, "No candidates");
+/// assert!(matches!(best, AdHocResult::Ok(2)));
+/// // Elements after the first `Ok` were never produced
+/// assert_eq!(produced, 3);
+///
+/// let degraded = vec![AdHocResult::Err("Diverged"), AdHocResult::AdHoc(1, "Not ideal")];
+/// assert!(matches!(fold_best(degraded, "No candidates"), AdHocResult::AdHoc(1, _)));
+///
+/// let empty: Vec<AdHocResult<i32, &str>> = Vec::new();
+/// assert!(matches!(fold_best(empty, "No candidates"), AdHocResult::Err("No candidates")));
+/// ```
+pub fn fold_best<T, E>(iter: impl IntoIterator<Item = AdHocResult<T, E>>, empty: E) -> AdHocResult<T, E> {
+    let mut best: Option<AdHocResult<T, E>> = None;
+    for result in iter {
+        if let AdHocResult::Ok(_) = result {
+            return result;
+        }
+        match &best {
+            Some(AdHocResult::AdHoc(..)) => {}
+            Some(_) if matches!(result, AdHocResult::Err(_)) => {}
+            _ => best = Some(result),
+        }
+    }
+    best.unwrap_or(AdHocResult::Err(empty))
+}
+
+/// Splits an iterator of results into two vectors by a predicate on their values.
+///
+/// Each element keeps its variant. `Ok` and `AdHoc` elements whose value matches
+/// `pred` go into the first vector, and all other elements into the second.
+/// `Err` elements carry no value to test, so they always go into the second vector.
+/// Order is preserved within each vector.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::{partition_by, AdHocResult};
+///
+/// let results = vec![
+///     AdHocResult::Ok(1),
+///     AdHocResult::AdHoc(20, "Not ideal"),
+///     AdHocResult::Err("Error"),
+///     AdHocResult::Ok(30),
+///     AdHocResult::AdHoc(2, "Not ideal"),
+/// ];
+///
+/// let (large, rest) = partition_by(results, |v| *v >= 10);
+///
+/// assert!(matches!(large.as_slice(), [AdHocResult::AdHoc(20, _), AdHocResult::Ok(30)]));
+/// assert!(matches!(
+///     rest.as_slice(),
+///     [AdHocResult::Ok(1), AdHocResult::Err("Error"), AdHocResult::AdHoc(2, _)]
+/// ));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn partition_by<T, E, P: FnMut(&T) -> bool>(
+    iter: impl IntoIterator<Item = AdHocResult<T, E>>,
+    mut pred: P,
+) -> (Vec<AdHocResult<T, E>>, Vec<AdHocResult<T, E>>) {
+    let mut matched = Vec::new();
+    let mut rest = Vec::new();
+    for result in iter {
+        let is_match = match &result {
+            AdHocResult::Ok(v) => pred(v),
+            AdHocResult::AdHoc(v, _) => pred(v),
+            AdHocResult::Err(_) => false,
+        };
+        if is_match {
+            matched.push(result);
+        } else {
+            rest.push(result);
+        }
+    }
+    (matched, rest)
+}
+
+/// Combines an iterator of results into a single result carrying every value and error.
+///
+/// Values are collected from `Ok` and `AdHoc` elements, and errors from `AdHoc` and
+/// `Err` elements, each in iteration order. The outcome is:
+///
+/// * `Err(errors)` if any element was an `Err`. The vector holds every error seen,
+///   including the warnings of `AdHoc` elements, and the collected values are dropped.
+/// * `AdHoc(values, warnings)` if there was no `Err` but at least one `AdHoc`.
+/// * `Ok(values)` if every element was an `Ok`.
+///
+/// The whole iterator is always consumed.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::{aggregate, AdHocResult};
+///
+/// let clean: Vec<AdHocResult<i32, &str>> = vec![AdHocResult::Ok(1), AdHocResult::Ok(2)];
+/// assert!(matches!(aggregate(clean), AdHocResult::Ok(v) if v == [1, 2]));
+///
+/// let degraded = vec![AdHocResult::Ok(1), AdHocResult::AdHoc(2, "Not ideal")];
+/// assert!(matches!(
+///     aggregate(degraded),
+///     AdHocResult::AdHoc(v, w) if v == [1, 2] && w == ["Not ideal"]
+/// ));
+///
+/// let failed = vec![
+///     AdHocResult::AdHoc(1, "Not ideal"),
+///     AdHocResult::Err("Error"),
+///     AdHocResult::Ok(3),
+/// ];
+/// assert!(matches!(aggregate(failed), AdHocResult::Err(e) if e == ["Not ideal", "Error"]));
+/// ```
+pub fn aggregate<T, E>(iter: impl IntoIterator<Item = AdHocResult<T, E>>) -> AdHocResult<Vec<T>, Vec<E>> {
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+    let mut failed = false;
+    for result in iter {
+        match result {
+            AdHocResult::Ok(v) => values.push(v),
+            AdHocResult::AdHoc(v, e) => {
+                values.push(v);
+                errors.push(e);
+            }
+            AdHocResult::Err(e) => {
+                failed = true;
+                errors.push(e);
+            }
+        }
+    }
+    if failed {
+        AdHocResult::Err(errors)
+    } else if errors.is_empty() {
+        AdHocResult::Ok(values)
+    } else {
+        AdHocResult::AdHoc(values, errors)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum Untagged<T, E> {
+    AdHoc { value: T, error: E },
+    Err { error: E },
+    Ok(T),
+}
+
+/// Deserializes an `AdHocResult` from an untagged "value or error object" form.
+///
+/// Intended for use with `#[serde(deserialize_with = "ad_hoc_result::untagged_adhoc")]`
+/// when ingesting payloads that do not use the tagged representation. The recognized
+/// shapes are tried in this order:
+///
+/// * an object with both `"value"` and `"error"` keys becomes `AdHoc(value, error)`;
+/// * an object with an `"error"` key but no `"value"` key becomes `Err(error)`;
+/// * anything else is parsed as a bare value and becomes `Ok(value)`.
+///
+/// Because the shapes are tried in order, a bare value of a type `T` that itself
+/// has an `"error"` field is read as an `AdHoc` or `Err`.
+///
+/// Requires the `serde` feature.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::AdHocResult;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Payload {
+///     #[serde(deserialize_with = "ad_hoc_result::untagged_adhoc")]
+///     result: AdHocResult<f64, String>,
+/// }
+///
+/// let ok: Payload = serde_json::from_str(r#"{"result": 1.5}"#).unwrap();
+/// assert!(matches!(ok.result, AdHocResult::Ok(1.5)));
+///
+/// let adhoc: Payload =
+///     serde_json::from_str(r#"{"result": {"value": 1.5, "error": "Not perfect"}}"#).unwrap();
+/// assert!(matches!(adhoc.result, AdHocResult::AdHoc(1.5, e) if e == "Not perfect"));
+///
+/// let err: Payload = serde_json::from_str(r#"{"result": {"error": "Diverged"}}"#).unwrap();
+/// assert!(matches!(err.result, AdHocResult::Err(e) if e == "Diverged"));
+/// ```
+#[cfg(feature = "serde")]
+pub fn untagged_adhoc<'de, D, T, E>(deserializer: D) -> Result<AdHocResult<T, E>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+    E: serde::Deserialize<'de>,
+{
+    let parsed = <Untagged<T, E> as serde::Deserialize>::deserialize(deserializer)?;
+    Ok(match parsed {
+        Untagged::AdHoc { value, error } => AdHocResult::AdHoc(value, error),
+        Untagged::Err { error } => AdHocResult::Err(error),
+        Untagged::Ok(value) => AdHocResult::Ok(value),
+    })
+}
+
+/// Concatenates the value vectors of an iterator of chunk results into one result.
+///
+/// The first `Err` chunk is returned immediately, and later chunks are not consumed.
+/// Otherwise all value vectors from `Ok` and `AdHoc` chunks are concatenated in order,
+/// and the result is `AdHoc(values, warning)` with the warning of the first `AdHoc`
+/// chunk if any chunk was degraded, or `Ok(values)` if every chunk was clean.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::{concat_results, AdHocResult};
+///
+/// let clean: Vec<AdHocResult<Vec<i32>, &str>> = vec![AdHocResult::Ok(vec![1, 2]), AdHocResult::Ok(vec![3])];
+/// assert!(matches!(concat_results(clean), AdHocResult::Ok(v) if v == [1, 2, 3]));
+///
+/// let degraded = vec![
+///     AdHocResult::Ok(vec![1]),
+///     AdHocResult::AdHoc(vec![2, 3], "First warning"),
+///     AdHocResult::AdHoc(vec![4], "Second warning"),
+/// ];
+/// assert!(matches!(
+///     concat_results(degraded),
+///     AdHocResult::AdHoc(v, "First warning") if v == [1, 2, 3, 4]
+/// ));
+///
+/// let failed = vec![
+///     AdHocResult::AdHoc(vec![1], "Not ideal"),
+///     AdHocResult::Err("Singular"),
+///     AdHocResult::Err("Diverged"),
+/// ];
+/// assert!(matches!(concat_results(failed), AdHocResult::Err("Singular")));
+/// ```
+pub fn concat_results<T, E>(chunks: impl IntoIterator<Item = AdHocResult<Vec<T>, E>>) -> AdHocResult<Vec<T>, E> {
+    let mut values = Vec::new();
+    let mut warning = None;
+    for chunk in chunks {
+        match chunk {
+            AdHocResult::Ok(v) => values.extend(v),
+            AdHocResult::AdHoc(v, e) => {
+                values.extend(v);
+                warning.get_or_insert(e);
+            }
+            AdHocResult::Err(e) => return AdHocResult::Err(e),
+        }
+    }
+    match warning {
+        Some(e) => AdHocResult::AdHoc(values, e),
+        None => AdHocResult::Ok(values),
+    }
+}
+
+/// Joins the `Display` output of every error in an iterator of results with `sep`.
+///
+/// Errors are taken from both `AdHoc` and `Err` elements, in iteration order.
+/// Returns `None` if no element carried an error.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::{combined_error_message, AdHocResult};
+///
+/// let results = vec![
+///     AdHocResult::Ok(1.0),
+///     AdHocResult::Err("col 3 singular"),
+///     AdHocResult::AdHoc(2.0, "col 7 ill-conditioned"),
+/// ];
+/// assert_eq!(
+///     combined_error_message(results, "; ").as_deref(),
+///     Some("col 3 singular; col 7 ill-conditioned")
+/// );
+///
+/// let clean: Vec<AdHocResult<f64, &str>> = vec![AdHocResult::Ok(1.0)];
+/// assert_eq!(combined_error_message(clean, "; "), None);
+/// ```
+pub fn combined_error_message<T, E>(iter: impl IntoIterator<Item = AdHocResult<T, E>>, sep: &str) -> Option<String>
+where
+    E: std::fmt::Display,
+{
+    let mut message: Option<String> = None;
+    for result in iter {
+        let e = match result {
+            AdHocResult::Ok(_) => continue,
+            AdHocResult::AdHoc(_, e) => e,
+            AdHocResult::Err(e) => e,
+        };
+        match &mut message {
+            Some(m) => {
+                m.push_str(sep);
+                m.push_str(&e.to_string());
+            }
+            None => message = Some(e.to_string()),
+        }
+    }
+    message
+}
+
+/// Like `aggregate`, but stops consuming the iterator after `max_errors` hard errors.
+///
+/// Values and errors are collected as in `aggregate`. As soon as the number of `Err`
+/// elements seen reaches `max_errors`, iteration stops and `Err(errors)` is returned
+/// with every error collected so far, including `AdHoc` warnings. A limit of `0`
+/// stops at the first `Err`, like a limit of `1`. If the limit is never reached,
+/// the outcome is exactly that of `aggregate`.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::{collect_until_errors, AdHocResult};
+///
+/// let mut consumed = 0;
+/// let results = (0..10).map(|i| {
+///     consumed += 1;
+///     if i % 2 == 1 { AdHocResult::Err(i) } else { AdHocResult::Ok(i) }
+/// });
+///
+/// let collected = collect_until_errors(results, 2);
+/// assert!(matches!(collected, AdHocResult::Err(e) if e == [1, 3]));
+/// // Iteration halted right after the second error
+/// assert_eq!(consumed, 4);
+///
+/// let few_errors = vec![AdHocResult::Ok(1), AdHocResult::AdHoc(2, "Not ideal")];
+/// assert!(matches!(
+///     collect_until_errors(few_errors, 2),
+///     AdHocResult::AdHoc(v, w) if v == [1, 2] && w == ["Not ideal"]
+/// ));
+/// ```
+pub fn collect_until_errors<T, E>(
+    iter: impl IntoIterator<Item = AdHocResult<T, E>>,
+    max_errors: usize,
+) -> AdHocResult<Vec<T>, Vec<E>> {
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+    let mut hard_errors = 0;
+    for result in iter {
+        match result {
+            AdHocResult::Ok(v) => values.push(v),
+            AdHocResult::AdHoc(v, e) => {
+                values.push(v);
+                errors.push(e);
+            }
+            AdHocResult::Err(e) => {
+                hard_errors += 1;
+                errors.push(e);
+                if hard_errors >= max_errors {
+                    return AdHocResult::Err(errors);
+                }
+            }
+        }
+    }
+    if hard_errors > 0 {
+        AdHocResult::Err(errors)
+    } else if errors.is_empty() {
+        AdHocResult::Ok(values)
+    } else {
+        AdHocResult::AdHoc(values, errors)
+    }
+}
+
+/// Keeps only the `Ok` elements of a vector in place, returning every dropped error.
+///
+/// Retention is strict: `AdHoc` elements are removed along with `Err` elements,
+/// and their recommended values are discarded. The returned vector holds the
+/// warnings of removed `AdHoc` elements and the errors of removed `Err` elements,
+/// in their original order. The remaining `Ok` elements also keep their order,
+/// and stay in the caller's buffer, so its capacity is preserved.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::{retain_usable, AdHocResult};
+///
+/// let mut results = vec![
+///     AdHocResult::Ok(1),
+///     AdHocResult::AdHoc(2, "Not ideal"),
+///     AdHocResult::Err("Error"),
+///     AdHocResult::Ok(4),
+/// ];
+///
+/// results.reserve(16);
+/// let capacity = results.capacity();
+///
+/// let dropped = retain_usable(&mut results);
+/// assert_eq!(dropped, ["Not ideal", "Error"]);
+/// assert!(matches!(results.as_slice(), [AdHocResult::Ok(1), AdHocResult::Ok(4)]));
+/// assert_eq!(results.capacity(), capacity);
+/// ```
+pub fn retain_usable<T, E>(results: &mut Vec<AdHocResult<T, E>>) -> Vec<E> {
+    results
+        .extract_if(.., |r| !r.is_ok())
+        .filter_map(AdHocResult::err)
+        .collect()
+}
+
+/// Splits an iterator of results into aligned value and error columns.
+///
+/// Both vectors have one entry per element, in iteration order, so index `i` of
+/// each column describes element `i`: an `Ok` gives `(Some(v), None)`, an `AdHoc`
+/// gives `(Some(v), Some(e))`, and an `Err` gives `(None, Some(e))`.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::{into_columns, AdHocResult};
+///
+/// let results = vec![
+///     AdHocResult::Ok(1),
+///     AdHocResult::AdHoc(2, "Not ideal"),
+///     AdHocResult::Err("Error"),
+/// ];
+///
+/// let (values, errors) = into_columns(results);
+/// assert_eq!(values, [Some(1), Some(2), None]);
+/// assert_eq!(errors, [None, Some("Not ideal"), Some("Error")]);
+/// ```
+pub fn into_columns<T, E>(iter: impl IntoIterator<Item = AdHocResult<T, E>>) -> (Vec<Option<T>>, Vec<Option<E>>) {
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+    for result in iter {
+        let (v, e) = match result {
+            AdHocResult::Ok(v) => (Some(v), None),
+            AdHocResult::AdHoc(v, e) => (Some(v), Some(e)),
+            AdHocResult::Err(e) => (None, Some(e)),
+        };
+        values.push(v);
+        errors.push(e);
+    }
+    (values, errors)
+}
+
+/// Asserts that at least `min_ratio` of a batch of results carry a usable value.
+///
+/// A result is usable if it is an `Ok` or `AdHoc`. An empty batch has nothing
+/// unusable in it and always passes.
+///
+/// # Panics
+///
+/// Panics if the ratio of usable results is below `min_ratio`, with a message
+/// giving the actual ratio and the count of each variant. The panic location is
+/// that of the caller.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::{assert_min_usable, AdHocResult};
+///
+/// let results = vec![
+///     AdHocResult::Ok(1),
+///     AdHocResult::AdHoc(2, "Not ideal"),
+///     AdHocResult::Ok(3),
+///     AdHocResult::Err("Error"),
+/// ];
+///
+/// assert_min_usable(&results, 0.75);
+///
+/// let payload = std::panic::catch_unwind(|| assert_min_usable(&results, 0.9)).unwrap_err();
+/// assert_eq!(
+///     payload.downcast_ref::<String>().unwrap(),
+///     "usable ratio 0.750 is below the minimum 0.900 (3 of 4 usable: 2 ok, 1 adhoc, 1 err)"
+/// );
+/// ```
+#[track_caller]
+pub fn assert_min_usable<T, E>(results: &[AdHocResult<T, E>], min_ratio: f64) {
+    let (mut ok, mut adhoc, mut err) = (0, 0, 0);
+    for result in results {
+        match result {
+            AdHocResult::Ok(_) => ok += 1,
+            AdHocResult::AdHoc(_, _) => adhoc += 1,
+            AdHocResult::Err(_) => err += 1,
+        }
+    }
+    let usable = ok + adhoc;
+    let ratio = if results.is_empty() { 1.0 } else { usable as f64 / results.len() as f64 };
+    if ratio < min_ratio {
+        panic!(
+            "usable ratio {ratio:.3} is below the minimum {min_ratio:.3} ({usable} of {} usable: {ok} ok, {adhoc} adhoc, {err} err)",
+            results.len()
+        );
+    }
+}
+
+/// Keeps the best result per key by the `Ok > AdHoc > Err` priority.
+///
+/// A later candidate replaces the stored result for its key only if it has a
+/// strictly better kind, so among candidates of equal kind the first one seen wins.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::{best_per_key, AdHocResult};
+///
+/// let candidates = vec![
+///     ("a", AdHocResult::Err("Diverged")),
+///     ("a", AdHocResult::AdHoc(1.0, "Not converged")),
+///     ("b", AdHocResult::Ok(2.0)),
+///     ("a", AdHocResult::AdHoc(1.5, "Not converged")),
+///     ("b", AdHocResult::Ok(2.5)),
+///     ("c", AdHocResult::Err("Singular")),
+/// ];
+///
+/// let best = best_per_key(candidates);
+/// assert_eq!(best["a"], AdHocResult::AdHoc(1.0, "Not converged"));
+/// assert_eq!(best["b"], AdHocResult::Ok(2.0));
+/// assert_eq!(best["c"], AdHocResult::Err("Singular"));
+/// ```
+pub fn best_per_key<K: Eq + std::hash::Hash, T, E>(
+    items: impl IntoIterator<Item = (K, AdHocResult<T, E>)>,
+) -> std::collections::HashMap<K, AdHocResult<T, E>> {
+    let mut best = std::collections::HashMap::new();
+    for (key, result) in items {
+        match best.entry(key) {
+            std::collections::hash_map::Entry::Vacant(slot) => {
+                slot.insert(result);
+            }
+            std::collections::hash_map::Entry::Occupied(mut slot) => {
+                if result.kind() < slot.get().kind() {
+                    slot.insert(result);
+                }
+            }
+        }
+    }
+    best
+}
+
+/// Returns every value if all results are clean, or every error otherwise.
+///
+/// This is the strict "everything must be clean" aggregator: `Ok(values)` is
+/// returned only if every element is an `Ok`. Even a single `AdHoc` routes the
+/// whole batch to the error list, which then holds the warnings of all `AdHoc`
+/// elements and the errors of all `Err` elements, in their original order. The
+/// iterator is always consumed in full so that the error list is complete.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::{all_or_errors, AdHocResult};
+///
+/// let clean: Vec<AdHocResult<u32, &str>> = vec![AdHocResult::Ok(1), AdHocResult::Ok(2)];
+/// assert_eq!(all_or_errors(clean), Ok(vec![1, 2]));
+///
+/// let degraded: Vec<AdHocResult<u32, &str>> = vec![AdHocResult::Ok(1), AdHocResult::AdHoc(2, "Not ideal")];
+/// assert_eq!(all_or_errors(degraded), Err(vec!["Not ideal"]));
+///
+/// let mixed: Vec<AdHocResult<u32, &str>> = vec![
+///     AdHocResult::Err("Too short"),
+///     AdHocResult::Ok(2),
+///     AdHocResult::AdHoc(3, "Not ideal"),
+/// ];
+/// assert_eq!(all_or_errors(mixed), Err(vec!["Too short", "Not ideal"]));
+/// ```
+pub fn all_or_errors<T, E>(iter: impl IntoIterator<Item = AdHocResult<T, E>>) -> Result<Vec<T>, Vec<E>> {
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+    for r in iter {
+        match r {
+            AdHocResult::Ok(v) => values.push(v),
+            AdHocResult::AdHoc(_, e) => errors.push(e),
+            AdHocResult::Err(e) => errors.push(e),
+        }
+    }
+    if errors.is_empty() {
+        Ok(values)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Decomposes each result of an iterator into a row of its kind, value and error.
+///
+/// Each row is a tuple `(kind, value, error)`:
+///
+/// * `Ok(v)` yields `(ResultKind::Ok, Some(v), None)`
+/// * `AdHoc(v, e)` yields `(ResultKind::AdHoc, Some(v), Some(e))`
+/// * `Err(e)` yields `(ResultKind::Err, None, Some(e))`
+///
+/// Rows are produced lazily and in order.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::{rows, AdHocResult, ResultKind};
+///
+/// let results: Vec<AdHocResult<u32, &str>> = vec![
+///     AdHocResult::Ok(1),
+///     AdHocResult::AdHoc(2, "Not ideal"),
+///     AdHocResult::Err("Error"),
+/// ];
+///
+/// let table: Vec<_> = rows(results).collect();
+/// assert_eq!(table, [
+///     (ResultKind::Ok, Some(1), None),
+///     (ResultKind::AdHoc, Some(2), Some("Not ideal")),
+///     (ResultKind::Err, None, Some("Error")),
+/// ]);
+/// ```
+pub fn rows<T, E>(iter: impl IntoIterator<Item = AdHocResult<T, E>>) -> impl Iterator<Item = (ResultKind, Option<T>, Option<E>)> {
+    iter.into_iter().map(|r| {
+        let kind = r.kind();
+        let (value, error) = r.into_parts();
+        (kind, value, error)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finite_ok(r: &AdHocResult<f64, &str>) -> bool {
+        !matches!(r, AdHocResult::Ok(v) if !v.is_finite())
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "`AdHocResult` failed validation")]
+    fn validated_panics_on_failed_check_with_debug_assertions() {
+        let bad: AdHocResult<f64, &str> = AdHocResult::Ok(f64::NAN);
+        let _ = bad.validated(finite_ok);
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn validated_passes_through_without_debug_assertions() {
+        let bad: AdHocResult<f64, &str> = AdHocResult::Ok(f64::NAN);
+        assert!(matches!(bad.validated(finite_ok), AdHocResult::Ok(v) if v.is_nan()));
     }
 }
@@ -0,0 +1,79 @@
+//! Helpers for numeric results, such as values recommended by numerical solvers.
+
+use crate::AdHocResult;
+
+/// Computes the mean and standard deviation of the values of a batch of results.
+///
+/// Both `Ok` values and `AdHoc` recommendations are included, and `Err` results are
+/// skipped. The standard deviation is the population standard deviation, dividing
+/// by the number of values. Returns `None` if no result carries a value.
+///
+/// No filtering of non-finite values is done: if any included value is NaN, both
+/// the mean and the standard deviation are NaN.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::AdHocResult;
+/// use ad_hoc_result::numeric::recommendation_stats;
+///
+/// let results = [
+///     AdHocResult::Ok(2.0),
+///     AdHocResult::AdHoc(4.0, "Ill-conditioned"),
+///     AdHocResult::Err("Singular"),
+///     AdHocResult::AdHoc(6.0, "Ill-conditioned"),
+/// ];
+/// let (mean, stddev) = recommendation_stats(&results).unwrap();
+/// assert_eq!(mean, 4.0);
+/// assert!((stddev - (8.0_f64 / 3.0).sqrt()).abs() < 1e-12);
+///
+/// let failed: [AdHocResult<f64, &str>; 1] = [AdHocResult::Err("Singular")];
+/// assert_eq!(recommendation_stats(&failed), None);
+///
+/// let with_nan = [AdHocResult::<f64, &str>::Ok(1.0), AdHocResult::Ok(f64::NAN)];
+/// let (mean, stddev) = recommendation_stats(&with_nan).unwrap();
+/// assert!(mean.is_nan() && stddev.is_nan());
+/// ```
+pub fn recommendation_stats<E>(results: &[AdHocResult<f64, E>]) -> Option<(f64, f64)> {
+    let values: Vec<f64> = results.iter().filter_map(|r| r.as_ref().ok().copied()).collect();
+    if values.is_empty() {
+        return None;
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+    Some((mean, variance.sqrt()))
+}
+
+/// Classifies a solution by the condition number of the system it solves.
+///
+/// The threshold is inclusive: the result is `Ok(solution)` when
+/// `condition_number <= tolerance`, and `AdHoc(solution, warn)` otherwise, so the
+/// solution is still recommended for an ill-conditioned system. A NaN condition
+/// number never compares below the tolerance and therefore yields `AdHoc`.
+///
+/// # Examples
+///
+/// ```
+/// use ad_hoc_result::AdHocResult;
+/// use ad_hoc_result::numeric::from_condition_number;
+///
+/// let well = from_condition_number([1.0, 2.0], 1e3, 1e8, "Ill-conditioned");
+/// assert_eq!(well, AdHocResult::Ok([1.0, 2.0]));
+///
+/// let boundary = from_condition_number(2.0, 1e8, 1e8, "Ill-conditioned");
+/// assert_eq!(boundary, AdHocResult::Ok(2.0));
+///
+/// let ill = from_condition_number(2.0, 1e12, 1e8, "Ill-conditioned");
+/// assert_eq!(ill, AdHocResult::AdHoc(2.0, "Ill-conditioned"));
+///
+/// let unknown = from_condition_number(2.0, f64::NAN, 1e8, "Ill-conditioned");
+/// assert_eq!(unknown, AdHocResult::AdHoc(2.0, "Ill-conditioned"));
+/// ```
+pub fn from_condition_number<T, E>(solution: T, condition_number: f64, tolerance: f64, warn: E) -> AdHocResult<T, E> {
+    if condition_number <= tolerance {
+        AdHocResult::Ok(solution)
+    } else {
+        AdHocResult::AdHoc(solution, warn)
+    }
+}